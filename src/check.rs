@@ -1,4 +1,5 @@
-use crate::model::argument::Lib;
+use crate::model::argument::{ChannelImpl,Lib,OnEnd};
+use crate::model::attribute::ActorAttributeArguments;
 use proc_macro_error::abort;
 
 pub fn is_imported( name: &str ){
@@ -15,7 +16,13 @@ pub fn is_imported( name: &str ){
     }
 }
 
-pub fn channels_import( lib: &Lib ){ 
+pub fn channels_import( lib: &Lib, channel_impl: &ChannelImpl ){ 
+
+    match channel_impl {
+        ChannelImpl::Std => (),
+        ChannelImpl::Crossbeam => { is_imported("crossbeam-channel"); },
+        ChannelImpl::Flume => { is_imported("flume"); },
+    }
 
     match lib {
         Lib::Tokio => (),
@@ -30,3 +37,26 @@ pub fn channels_import( lib: &Lib ){
         }
     }
 }
+
+// the crates used by the code of the options
+pub fn options_import( aaa: &ActorAttributeArguments ){
+
+    if aaa.events.active() && matches!(aaa.lib, Lib::AsyncStd | Lib::Smol) {
+        is_imported("async-broadcast");
+    }
+
+    if aaa.trace {
+        is_imported("tracing");
+    }
+
+    match aaa.on_end {
+        OnEnd::Log     => { is_imported("log"); },
+        OnEnd::Tracing => { is_imported("tracing"); },
+        _ => (),
+    }
+
+    if aaa.serde.active() {
+        is_imported("serde");
+        is_imported("serde_json");
+    }
+}
@@ -189,6 +189,19 @@ pub static AVAIL_LIB:&'static str = "
 *  -  default
 ";
 
+pub static AVAIL_CHANNEL_IMPL: &str = "
+\navailable 'channel_impl' options:
+
+*   \"std\"
+    \"crossbeam\"
+    \"flume\"
+
+    The crate of the mailbox of a `lib = \"std\"` actor, \
+    `crossbeam-channel` or `flume` is required by the user crate.
+
+*  -  default
+";
+
 pub static AVAIL_EDIT: &'static str = "
 \navailable 'edit' options:
          
@@ -268,6 +281,212 @@ pub static AVAIL_DEBUT: &'static str = "
     saved upon the last instance being dropped.
 ";
 
+pub static AVAIL_SUPERVISE: &str = "
+\navailable 'supervise' options:
+    supervise
+        (
+            restart = n (usize)
+           strategy = \"one_for_one\" *
+            factory = method_name
+         on_restart = method_name
+        )
+
+    `restart` limits the number of restarts (unlimited if omitted). \
+    `factory` names an associated function `fn() -> Self` used to \
+    rebuild the actor instead of `new`, by default `new` is called with \
+    clones of its arguments, which have to be owned `Clone + Send + 'static` \
+    types. `on_restart` names a method `fn(&mut self, usize)` called on \
+    the rebuilt actor.
+";
+
+pub static AVAIL_SHUTDOWN: &str = "
+\navailable 'shutdown' options:
+    shutdown
+        (
+         on_stop = method_name
+        )
+
+    `on_stop` names a method `fn(&mut self)` called on the actor's \
+    thread (task) when the `play` loop ends.
+";
+
+pub static DURATION_FORMAT: &str = "
+    A duration is an integer followed by a unit: 
+    \"ns\", \"us\", \"ms\", \"s\", \"m\" or \"h\", for instance \"250ms\".
+";
+
+pub static AVAIL_TIMEOUT: &str = "
+\navailable 'timeout' options:
+    timeout = \"1s\"
+
+    timeout
+        (
+         default     = \"1s\",
+         method_name = \"100ms\",
+         ..
+        )
+
+    Applies to the methods that return a type. \
+    A duration is an integer followed by a unit: \
+    \"ns\", \"us\", \"ms\", \"s\", \"m\" or \"h\". \
+    The timed methods return a `Result` with the `Timeout` error, \
+    with or without the 'fallible' option. \
+    A duration must be greater than zero. \
+    A method named `default` is listed as `r#default = \"100ms\"`.
+";
+
+pub static AVAIL_CAST: &str = "
+\navailable 'cast' options:
+    cast
+
+    cast
+        (
+         method_name,
+         ..
+        )
+
+    Applies to the methods that return a type. \
+    Without a list every such method gets a `cast_` counterpart.
+";
+
+pub static AVAIL_DEFERRED: &str = "
+\navailable 'deferred' options:
+    deferred
+
+    deferred
+        (
+         method_name,
+         ..
+        )
+
+    Applies to the methods that return a type. \
+    Without a list every such method gets a `_deferred` counterpart. \
+    With a bounded channel (`channel = n`) the `_deferred` method is \
+    `async`, it awaits room for the message and returns the future of the reply.
+";
+
+pub static AVAIL_PRIORITY: &str = "
+\navailable 'priority' options:
+    priority
+        (
+         high( method_name, .. )
+        )
+
+    The listed methods are sent through a separate channel \
+    which the `play` loop drains before the main one.
+";
+
+pub static AVAIL_TICK: &str = "
+\navailable 'tick' options:
+    tick
+        (
+         every  = \"250ms\",
+         method = method_name
+        )
+
+    `method` names a method `fn(&mut self)` called by the `play` loop \
+    between messages, every time the `every` period elapses. 
+    A duration is an integer followed by a unit: \
+    \"ns\", \"us\", \"ms\", \"s\", \"m\" or \"h\".
+";
+
+pub static AVAIL_HOOKS: &str = "
+\navailable 'hooks' options:
+    hooks
+        (
+         start  = method_name,
+         stop   = method_name,
+         before = method_name,
+         after  = method_name
+        )
+
+    `start` and `stop` name methods `fn(&mut self)` called on the \
+    actor's thread (task) before the first and after the last message. \
+    `before` and `after` name methods `fn(&mut self, &'static str)` \
+    called around every message with the name of its `Script` variant.
+";
+
+pub static AVAIL_POOL: &str = "
+\navailable 'pool' options:
+    pool
+        (
+         size     = n (usize)
+         dispatch = \"round_robin\" *
+                    \"least_queued\"
+                    \"key_hash\"
+         key      = argument_name
+        )
+
+    Spawns `size` instances of the actor, each one created by `new`, \
+    behind a single live instance. `key_hash` dispatch requires `key`, \
+    the name of a method argument whose hash selects the instance, \
+    the methods without such an argument are dispatched round robin.
+";
+
+pub static AVAIL_EVENTS: &str = "
+\navailable 'events' options:
+    events( Type )
+
+    events( Type, capacity = n (usize) )
+
+    Requires `interact`. An actor method takes an `inter_emit: impl Fn(Type)` \
+    argument to broadcast events to the receivers returned by `subscribe`. \
+    `capacity` (64 by default) bounds the `tokio` and `async-broadcast` channels.
+";
+
+pub static AVAIL_SERDE: &str = "
+\navailable 'serde' options:
+    serde
+
+    serde( journal = \"file_name.jsonl\" )
+
+    Derives `Serialize` and `Deserialize` for the `Script` enum, \
+    `serde` (with `derive`) and `serde_json` are required by the user crate. \
+    `journal` appends every message handled by `play` to the file, \
+    `Script::replay` hands the messages of a journal to an actor.
+";
+
+pub static AVAIL_THREAD: &str = "
+\navailable 'thread' options:
+    thread
+
+    thread( name = \"thread_name\", stack_size = n (usize) )
+
+    Spawns the `play` thread of a `lib = \"std\"` actor with \
+    `std::thread::Builder`. The name defaults to the actor name \
+    (followed by the debut in milliseconds if `debut` is active), \
+    Linux truncates the name seen by the system to 15 bytes. A failed spawn is \
+    returned by a `new` returning `Result` (the error type implements \
+    `From<std::io::Error>`) or `Option`, otherwise it panics.
+";
+
+pub static AVAIL_SPAWNER: &str = "
+\navailable 'spawner' options:
+    spawner
+
+    spawner = path::to::function
+
+    Spawns the `play` loop of an async actor with the function instead \
+    of `tokio::spawn`, `smol::spawn` or `async_std::task::spawn`, \
+    the function has the same signature as the library `spawn`. \
+    The `tokio` and `smol` actors get a `new_on` constructor taking \
+    a `tokio::runtime::Handle` or a `&smol::Executor` as first argument.
+";
+
+pub static AVAIL_ON_END: &str = "
+\navailable 'on_end' options:
+    on_end = \"stderr\" *
+             \"silent\"
+             \"log\"
+             \"tracing\"
+             function_name
+
+    Reports the end of the `play` loop. \"log\" and \"tracing\" require \
+    the respective crate. A function \
+    `fn(&'static str, Option<std::time::SystemTime>, ActorNameEnd)` \
+    receives the actor name, the debut and the exit reason.
+";
+
 pub static AVAIL_ACTOR: &'static str = "
 #[interthread::actor( 
     
@@ -279,6 +498,10 @@ pub static AVAIL_ACTOR: &'static str = "
               \"tokio\"
               \"async_std\"
 
+channel_impl = \"std\" *
+               \"crossbeam\"
+               \"flume\"
+
         edit( 
              script(..)
              live(..)
@@ -294,6 +517,97 @@ pub static AVAIL_ACTOR: &'static str = "
              legend
             ) 
     interact
+
+    fallible
+
+    handle
+
+    try_send
+
+       with
+
+       weak
+
+   registry
+
+    metrics
+
+      trace
+
+     remote
+
+    timeout = \"1s\"
+              (
+               default = \"1s\",
+               method_name = \"100ms\"
+              )
+
+       cast
+            (
+             method_name,
+             ..
+            )
+
+   deferred
+            (
+             method_name,
+             ..
+            )
+
+   priority(
+            high( method_name, .. )
+           )
+
+       tick(
+            every  = \"250ms\",
+            method = method_name
+           )
+
+      hooks(
+            start  = method_name,
+            stop   = method_name,
+            before = method_name,
+            after  = method_name
+           )
+
+       pool(
+            size     = n (usize)
+            dispatch = \"round_robin\" *
+                       \"least_queued\"
+                       \"key_hash\"
+            key      = argument_name
+           )
+
+     events( Type, capacity = n (usize) )
+
+      serde(
+            journal = \"file_name.jsonl\"
+           )
+
+     thread(
+            name       = \"thread_name\"
+            stack_size = n (usize)
+           )
+
+    spawner
+    spawner = path::to::function
+
+     on_end = \"stderr\" *
+              \"silent\"
+              \"log\"
+              \"tracing\"
+              function_name
+
+  supervise(
+            restart = n (usize)
+           strategy = \"one_for_one\" *
+            factory = method_name
+         on_restart = method_name
+           )
+
+   shutdown(
+            on_stop = method_name
+           )
 )]
 
 *  -  default 
@@ -318,6 +632,16 @@ AA     debut(
              legend
             )   
 
+AA     fallible
+
+AA     handle
+
+AA     try_send
+
+AA     shutdown(
+             on_stop = method_name
+            )
+
 (AA)   assoc(
              self::assoc,
              ..
@@ -539,6 +863,140 @@ impl OriginVars {
 pub static LEGEND_LIMIT_GENERIC: &str = 
 "   The 'legend' option is not supported for generic objects.";
 
+pub static HANDLE_LEGEND: &str = 
+"   The 'handle' option can not be combined with `debut(legend)`.";
+
+pub static TRY_SEND_UNBOUNDED: &str = 
+"   The 'try_send' option requires a bounded channel (`channel = n`).";
+
+pub static TRY_SEND_NAME_NOTE: &str = 
+"   The 'try_send' option generates a `try_` method for every `live` method.";
+
+pub static CAST_NAME_NOTE: &str = 
+"   The 'cast' option generates a `cast_` method for every listed `live` method.";
+
+pub static TICK_REQUIRED: &str = 
+"   The 'tick' option requires both `every` and `method`.";
+
+pub static TICK_ZERO: &str = 
+"   The 'tick' option requires a duration `every` greater than zero.";
+
+pub static TICK_RANGE: &str = 
+"   The duration `every` of the 'tick' option is too long, the limit is about 584 years.";
+
+pub static TIMEOUT_ZERO: &str = 
+"   The 'timeout' option requires durations greater than zero.";
+
+pub static TIMEOUT_RANGE: &str =
+"   A duration of the 'timeout' option is too long, the limit is about 584 years.";
+
+pub static HOOKS_REQUIRED: &str = 
+"   The 'hooks' option requires at least one of `start`, `stop`, `before` or `after`.";
+
+pub static HOOKS_STOP: &str = 
+"   The options `hooks(stop)` and `shutdown(on_stop)` both name the method called after the last message, use one of them.";
+
+pub static WEAK_LEGEND: &str = 
+"   The 'weak' option can not be combined with `debut(legend)`.";
+
+pub static METRICS_PRIORITY: &str = 
+"   The 'metrics' option can not be combined with `priority` or `debut(legend)`.";
+
+pub static REGISTRY_LEGEND: &str = 
+"   The 'registry' option can not be combined with `debut(legend)`.";
+
+pub static REGISTRY_LIMIT_GENERIC: &str = 
+"   The 'registry' option is not supported for generic objects.";
+
+pub static SERDE_LIMIT_GENERIC: &str = 
+"   The 'serde' option is not supported for generic objects.";
+
+pub static CHANNEL_IMPL_LIB: &str = 
+"   The 'channel_impl' option is supported only for `lib = \"std\"` actors.";
+
+pub static THREAD_LIB: &str = 
+"   The 'thread' option is supported only for `lib = \"std\"` actors.";
+
+pub static SPAWNER_LIB: &str = 
+"   The 'spawner' option is supported only for async actors, see the 'thread' option for `lib = \"std\"` actors.";
+
+pub static SPAWNER_ASYNC_STD: &str = 
+"   The 'spawner' option requires a function for `lib = \"async_std\"` actors, there is no `new_on` constructor.";
+
+pub fn spawner_new_on_taken(ident: &Ident) -> String {
+    format!("   The 'spawner' option generates the `{ident}` constructor, the actor has a method with the same name.")
+}
+
+pub static REMOTE_SERDE: &str = 
+"   The 'remote' option requires `serde`.";
+
+pub static SERDE_COMBINE: &str = 
+"   The 'serde' option can not be combined with `with`, `trace` or `interact`, \
+their messages can not be serialized.";
+
+pub static EVENTS_INTERACT: &str = 
+"   The 'events' option requires `interact`, the events are emitted through the `inter_emit` variable.";
+
+pub static POOL_SIZE: &str = 
+"   The 'pool' option requires a `size` greater than zero.";
+
+pub static POOL_KEY: &str = 
+"   The `key` of the 'pool' option is required by, and only by, `dispatch = \"key_hash\"`.";
+
+pub static POOL_COMBINE: &str = 
+"   The 'pool' option can not be combined with `handle`, `shutdown`, `priority`, \
+`supervise`, `interact`, `try_send`, `tick`, `with`, `deferred` or `debut(legend)`.";
+
+pub static POOL_PATTERN_ARG: &str = 
+"   The 'pool' option creates every instance by cloning the arguments \
+of `new`, which requires plain identifier patterns.";
+
+pub static PRIORITY_LEGEND: &str = 
+"   The 'priority' option can not be combined with `debut(legend)`.";
+
+pub static DEFERRED_LIB: &str = 
+"   The 'deferred' option requires an async library (`lib = \"tokio\"`, `\"async_std\"` or `\"smol\"`).";
+
+pub static DEFERRED_NAME_NOTE: &str = 
+"   The 'deferred' option generates a `_deferred` method for every listed `live` method.";
+
+pub static IMPL_TRAIT_HELP: &str = "
+    The `impl Trait` types of the methods sent to the actor are \
+boxed as `Box<dyn Trait + Send + 'static>` in the `Script` enum. \
+Boxing is supported for a single trait implemented by its own `Box`:
+    Fn, FnMut, FnOnce, 
+    Iterator, DoubleEndedIterator, ExactSizeIterator, FusedIterator, 
+    Debug, Display, 
+    Read, Write, BufRead, Seek, Hasher
+
+    For other traits consider a generic parameter or \
+an explicit `Box<dyn Trait + Send>` type.
+";
+
+pub fn impl_trait_not_boxed(ty: &TokenStream, reason: &str) -> String {
+    format!("   Type `{ty}` can not be sent to the actor, {reason}.")
+}
+
+pub static IMPL_TRAIT_PATTERN: &str = 
+"   An `impl Trait` argument requires a plain identifier pattern.";
+
+pub static SUPERVISE_LIB: &str = 
+"   The 'supervise' option is supported only for `lib = \"std\"` actors.";
+
+pub static SUPERVISE_LEGEND: &str = 
+"   The 'supervise' option can not be combined with `debut(legend)`.";
+
+pub static SUPERVISE_BORROWED_ARG: &str = 
+"   The 'supervise' option rebuilds the actor by calling `new` with clones \
+of its arguments, kept for the life of the actor, which requires owned \
+`Clone + Send + 'static` arguments. \
+Consider an owned type (`String` instead of `&str`) or providing a `factory` method.";
+
+pub static SUPERVISE_PATTERN_ARG: &str = 
+"   The 'supervise' option rebuilds the actor by cloning the arguments \
+of `new`, which requires plain identifier patterns. \
+Consider renaming the pattern or providing a `factory` method.";
+
 pub static INTERACT_VARS_HELP: &str = "
     The `interact` option is designed to provide the model with \
 comprehensive non-blocking functionality, along with convenient \
@@ -201,6 +201,13 @@
 //! 
 //! > **Note**: Method `new` not included as a variant in the `script`. 
 //! 
+//! An `impl Trait` argument or return type can not be a field type, 
+//! so it is boxed as `Box<dyn Trait + Send + 'static>` in the variant, 
+//! the `live` method keeps the `impl Trait` signature. 
+//! This applies to the traits implemented by their own `Box`, like 
+//! `Fn`, `FnMut`, `FnOnce`, `Iterator`, `Debug`, `Display` or `Error`, 
+//! other traits are reported with a compile error pointing at the type.
+//! 
 //! 
 //! # direct
 //! The implementation block of `script`struct, specifically 
@@ -356,6 +363,8 @@
 //! Generally, such issues are unlikely to occur, but 
 //! if the `interact` option is used, it introduces a 
 //! potential scenario for encountering this situation.
+//! Use the [`fallible`](./attr.actor.html#fallible) option 
+//! to receive an error instead.
 //!  
 //! 
 //! # Macro Implicit Dependencies
@@ -696,6 +705,10 @@ pub fn example( attr: proc_macro::TokenStream, _item: proc_macro::TokenStream )
 ///              "tokio"
 ///              "async_std"
 ///
+/// channel_impl = "std" *
+///                "crossbeam"
+///                "flume"
+///
 ///        edit( 
 ///             script(..)
 ///             live(..)
@@ -711,6 +724,97 @@ pub fn example( attr: proc_macro::TokenStream, _item: proc_macro::TokenStream )
 ///             legend
 ///            ) 
 ///    interact
+///
+///    fallible
+///
+///     handle
+///
+///   try_send
+///
+///       with
+///
+///       weak
+///
+///   registry
+///
+///    metrics
+///
+///      trace
+///
+///     remote
+///
+///    timeout = "1s"
+///              (
+///               default = "1s",
+///               method_name = "100ms"
+///              )
+///
+///       cast
+///            (
+///             method_name,
+///             ..
+///            )
+///
+///   deferred
+///            (
+///             method_name,
+///             ..
+///            )
+///
+///   priority(
+///            high( method_name, .. )
+///           )
+///
+///       tick(
+///            every  = "250ms",
+///            method = method_name
+///           )
+///
+///      hooks(
+///            start  = method_name,
+///            stop   = method_name,
+///            before = method_name,
+///            after  = method_name
+///           )
+///
+///       pool(
+///            size     = n (usize)
+///            dispatch = "round_robin" *
+///                       "least_queued"
+///                       "key_hash"
+///            key      = argument_name
+///           )
+///
+///     events( Type, capacity = n (usize) )
+///
+///      serde(
+///            journal = "file_name.jsonl"
+///           )
+///
+///     thread(
+///            name       = "thread_name"
+///            stack_size = n (usize)
+///           )
+///
+///    spawner
+///    spawner = path::to::function
+///
+///     on_end = "stderr" *
+///              "silent"
+///              "log"
+///              "tracing"
+///              function_name
+///
+///  supervise(
+///            restart = n (usize)
+///           strategy = "one_for_one" *
+///            factory = method_name
+///         on_restart = method_name
+///           )
+///
+///   shutdown(
+///            on_stop = method_name
+///           )
 ///)]
 ///
 ///*  -  default 
@@ -723,12 +827,36 @@ pub fn example( attr: proc_macro::TokenStream, _item: proc_macro::TokenStream )
 ///
 /// - [`channel`](#channel)
 /// - [`lib`](#lib) 
+/// - [`channel_impl`](#channel_impl)
+/// - [`thread`](#thread)
+/// - [`spawner`](#spawner)
 /// - [`edit`](#edit)
 /// - [`file`](#file)
 /// - [`name`](#name)
 /// - [`assoc`](#assoc)
 /// - [`debut`](#debut)
 /// - [`interact`](#interact)
+/// - [`fallible`](#fallible)
+/// - [`supervise`](#supervise)
+/// - [`shutdown`](#shutdown)
+/// - [`handle`](#handle)
+/// - [`try_send`](#try_send)
+/// - [`timeout`](#timeout)
+/// - [`cast`](#cast)
+/// - [`deferred`](#deferred)
+/// - [`with`](#with)
+/// - [`priority`](#priority)
+/// - [`tick`](#tick)
+/// - [`hooks`](#hooks)
+/// - [`pool`](#pool)
+/// - [`events`](#events)
+/// - [`weak`](#weak)
+/// - [`registry`](#registry)
+/// - [`metrics`](#metrics)
+/// - [`trace`](#trace)
+/// - [`on_end`](#on_end)
+/// - [`serde`](#serde)
+/// - [`remote`](#remote)
 ///
 /// 
 /// 
@@ -792,6 +920,117 @@ pub fn example( attr: proc_macro::TokenStream, _item: proc_macro::TokenStream )
 /// 
 /// 
 /// 
+/// # channel_impl
+///
+/// The `channel_impl` argument selects the crate of the mailbox of 
+/// a `lib = "std"` actor. The `Live` sender (the `sender` field) and 
+/// the receiver of the `play` loop become the channel types of the crate, 
+/// with their `len`, `is_empty` and multi-consumer receivers.
+/// The reply channel remains the `oneshot` crate.
+///
+/// - `"std"` (default) `std::sync::mpsc`
+/// - `"crossbeam"` the `crossbeam-channel` crate
+/// - `"flume"` the `flume` crate
+///
+/// The selected crate is required as a dependency of the user crate.
+///
+///## Examples
+///```rust
+///pub struct MyActor(u32);
+///
+///#[interthread::actor( channel_impl = "crossbeam", channel = 10 )]
+///impl MyActor{
+///    pub fn new() -> Self{ Self(0) }
+///    pub fn add(&mut self, v: u32) -> u32 { self.0 += v; self.0 }
+///}
+///
+///fn main(){
+///    let mut actor = MyActorLive::new();
+///    assert_eq!(actor.add(2), 2);
+///
+///    let sender: &crossbeam_channel::Sender<MyActorScript> = &actor.sender;
+///    assert!(sender.is_empty());
+///}
+///```
+/// 
+/// 
+/// 
+/// # thread
+///
+/// The `thread` argument spawns the `play` loop of a `lib = "std"` 
+/// actor with `std::thread::Builder`, the thread gets a name, visible 
+/// in panic messages, debuggers and profilers.
+///
+/// - `name` the name of the thread, by default the actor name 
+/// followed by the debut in milliseconds since the UNIX epoch when 
+/// `debut` is active (`"MyActor-1700000000000"`).
+/// - `stack_size` the stack size of the thread in bytes.
+///
+/// Linux truncates the thread name seen by the system (`top`, `gdb`, 
+/// `/proc`) to 15 bytes, keep the leading part of the name distinctive. 
+/// `std::thread::current().name()` returns the full name.
+///
+/// A failed spawn is returned by a `new` returning `Option` (`None`)
+/// or `Result`, the error type of the latter implements 
+/// `From<std::io::Error>`. A `new` returning `Self` panics.
+///
+///## Examples
+///```rust
+///pub struct MyActor;
+///
+///#[interthread::actor( thread( name = "worker", stack_size = 65536 ) )]
+///impl MyActor{
+///    pub fn new() -> Self{ Self }
+///    pub fn thread_name(&self) -> Option<String> { 
+///        std::thread::current().name().map(String::from)
+///    }
+///}
+///
+///fn main(){
+///    let actor = MyActorLive::new();
+///    assert_eq!(actor.thread_name().as_deref(), Some("worker"));
+///}
+///```
+/// 
+/// 
+/// 
+/// # spawner
+///
+/// The `play` loop of an async actor is spawned with `tokio::spawn`,
+/// `smol::spawn` or `async_std::task::spawn`, the first one panics 
+/// outside of a runtime context. The `spawner` argument places the 
+/// actor on a dedicated runtime, an executor or a `tokio::task::LocalSet`.
+///
+/// - `spawner = path::to::function` names a function with the signature 
+/// of the library `spawn` used instead (`spawner = tokio::task::spawn_local`).
+/// With the `handle` option the function returns the handle of 
+/// the library (`JoinHandle<()>`, `smol::Task<()>`).
+/// - `spawner`, as well as the form above, generates for the `tokio` and 
+/// `smol` actors a `new_on` constructor (`try_new_on` for a `try_new`), 
+/// the `new` function taking a `tokio::runtime::Handle` or 
+/// a `&smol::Executor` as first argument. 
+///
+///## Examples
+///```rust
+///pub struct MyActor(u32);
+///
+///#[interthread::actor( lib = "tokio", spawner )]
+///impl MyActor{
+///    pub fn new(v: u32) -> Self{ Self(v) }
+///    pub fn get(&self) -> u32 { self.0 }
+///}
+///
+///fn main(){
+///    let io = tokio::runtime::Runtime::new().unwrap();
+///
+///    // no runtime context required
+///    let actor = MyActorLive::new_on(io.handle().clone(), 3);
+///    assert_eq!(io.block_on(actor.get()), 3);
+///}
+///```
+/// 
+/// 
+/// 
 /// # edit
 ///
 /// The `edit` argument specifies the available editing options.
@@ -1331,188 +1570,1340 @@ pub fn example( attr: proc_macro::TokenStream, _item: proc_macro::TokenStream )
 /// getter call is happening inside providing the required type. 
 /// It will work for any custom getter as long as it adheres to rule 3.
 /// 
+/// # fallible
 /// 
+/// By default the `live` methods panic if the actor is no longer 
+/// running, for instance when one of the actor's methods has 
+/// panicked. The `fallible` option makes every `live` method that 
+/// goes through the channel return a `Result`, leaving it to 
+/// the caller to decide what to do with a dead actor.
 /// 
+/// Since a proc-macro crate can not export types, the error type
+/// is generated along with the model and named after the `live` 
+/// struct convention `ActorName` + `Error`
+/// ( `GroupName` + `GroupError` for `group` ):
 /// 
+/// - `SendClosed`   - the actor is no longer receiving messages.
+/// - `ReplyDropped` - the actor dropped the message before sending a reply.
 /// 
-
-
-#[proc_macro_error::proc_macro_error]
-#[proc_macro_attribute]
-pub fn actor( attr: proc_macro::TokenStream, item: proc_macro::TokenStream ) -> proc_macro::TokenStream {
-    
-    let item_impl = syn::parse_macro_input!(item as syn::ItemImpl);
-
-    let mut aaa = model::attribute::ActorAttributeArguments::default();
-    let nested  = syn::parse_macro_input!(attr with syn::punctuated::Punctuated::<syn::Meta,syn::Token![,]>::parse_terminated); 
-    aaa.parse_nested(nested);
-    aaa.cross_check();
-
-    check::channels_import( &aaa.lib );
-
-    let edit_attr = aaa.edit.attr.clone();
-
-    let aa = crate::model::AttributeArguments::Actor(aaa);
-
-    let model_sdpl = crate::model::generate_model( aa,&item_impl,None);
-    
-    let (_,edit_sdpl) = model_sdpl.split();
-
-    if let Some( edit_attr ) = edit_attr {
-
-        parse::edit_write( &edit_attr, &item_impl, edit_sdpl);
-    }
-
-    let (code,_) = model_sdpl.get_code_edit();
-
-    quote::quote!{
-        #item_impl
-        #code
-    }.into()
-
-
-}
-
-
-/// ## A set of actors sharing  a single thread
-///
-/// In the realm of concurrent programming, creating a separate thread 
-/// for each individual actor can sometimes incur a significant overhead. 
-/// In such scenarios, developers may opt to populate an `actor`'s 
-/// definition with complex encapsulations (potential actors), 
-/// effectively creating a collection of objects running within 
-/// a single thread. While this approach proves resource-efficient, 
-/// it does come with a trade-off: accessing the methods of field 
-/// types must be explicitly written within the main `actor` 
-/// implementation block.
-/// 
-/// This is where the `group` macro comes into play. A `group` is a 
-/// set of `actors` that share a single thread, consisting of 
-/// a `main-actor` and `group-actors` contained within the 
-/// `main-actor`'s fields. Developers can now bypass the need to rewrite 
-/// method wrappers, gaining direct access to `group-actor` methods via 
-/// dot notation, as seamlessly as if these `group-actors` were `actors` 
-/// in their own right.
-/// 
-/// In this scenario, the methods of the `main-actor` take on the responsibility 
-/// for interaction within and between `group-actors`, while the latter primarily 
-/// serve to export their functionality. 
-/// 
-/// Before delving into further details, let's explore an example of a `group`. 
-/// Assuming there is a good understanding of how an `actor` operates, once the 
-/// `Live` instance is returned after invoking the `new` method, the `actor` is 
-/// already running in a separate thread. Therefore, there’s no need to 
-/// complicate the example with extra thread spawning just for visual clarity. 
-/// 
+/// Associated functions included by `assoc` do not use the channel
+/// and keep their signatures.
 /// 
 /// ## Examples
-///  
 ///```rust
+///pub struct MyActor(u8);
 ///
-///// We have `Aa` and `Bb`
-///pub struct Aa(u8);
-///impl Aa {
-///    pub fn add(&mut self, v: u8){
-///        self.0 += v;
-///    }
-///}
+///#[interthread::actor( fallible )] 
+///impl MyActor {
 ///
-///pub struct Bb(u8);
-///impl Bb {
-///    pub fn add(&mut self, v: u8){
-///        self.0 += v;
+///    pub fn new() -> Self { Self(0) } 
+///
+///    pub fn set(&mut self, v: u8){
+///        self.0 = v;
+///    }
+///    pub fn get(&self) -> u8 {
+///        self.0
 ///    }
 ///}
 ///
-///// Definition of group
-///pub struct AaBb {
-///    pub a: Aa,
-///    pub b: Bb,
+///fn main () {
+///
+///    let mut actor = MyActorLive::new();
+///
+///    // the signatures are different 
+///    let set: Result<(), MyActorError> = actor.set(5);
+///    let get: Result<u8, MyActorError> = actor.get();
+///
+///    assert_eq!(set, Ok(()));
+///    assert_eq!(get, Ok(5));
 ///}
+///```
+/// 
+/// # supervise
+/// 
+/// When one of the actor's methods panics, the `play` loop unwinds 
+/// and the actor is gone. The `supervise` option catches the panic 
+/// (`std::panic::catch_unwind` around `direct`), rebuilds the actor 
+/// and re-enters the `play` loop, so the `live` instances keep working.
+/// Available for `lib = "std"` only.
+/// 
+/// - `restart`    - maximum number of restarts, unlimited if omitted. 
+/// Once the limit is reached the panic resumes and the actor ends.
+/// - `strategy`   - `"one_for_one"` (default), only the panicked actor is restarted.
+/// - `factory`    - name of an associated function `fn() -> Self` used 
+/// to rebuild the actor. By default the actor is rebuilt by calling 
+/// `new` or `try_new` with clones of the original arguments, which 
+/// therefore have to be owned `Clone + Send + 'static` types (a borrowed
+/// argument like `&str` is rejected). If `try_new` fails 
+/// the panic resumes and the actor ends.
+/// - `on_restart` - name of a method `fn(&mut self, usize)` called on 
+/// the rebuilt actor with the number of restarts so far. 
+/// Keep it private so it's not included in the `live` methods.
+/// 
+/// The message that caused the panic is lost, a caller waiting 
+/// for its reply will panic (see [`fallible`](#fallible)).
+/// 
+/// ## Examples
+///```rust
+///pub struct MyActor{ value: u8, restarts: usize }
 ///
-///#[interthread::group( file= "path/to/file.rs")]
-///impl AaBb {
+///#[interthread::actor( supervise(restart = 3, on_restart = restarted) )] 
+///impl MyActor {
 ///
-///    pub fn new( ) -> Self {
-///        let a = Aa(0);
-///        let b = Bb(0);
-///        Self{ a,b}
-///    }
+///    pub fn new(value: u8) -> Self { Self{ value, restarts: 0 } } 
 ///
-///    pub fn add(&mut self, v:u8){
-///        self.a.0 += v;
-///        self.b.0 += v;
+///    pub fn set(&mut self, v: u8){
+///        self.value = v;
 ///    }
-///
-///    pub fn get_value(&mut self) -> (u8,u8) {
-///        (self.a.0,self.b.0)
+///    pub fn get(&self) -> (u8,usize) {
+///        (self.value, self.restarts)
+///    }
+///    pub fn crash(&self){
+///        panic!("crash");
+///    }
+///    fn restarted(&mut self, restarts: usize){
+///        self.restarts = restarts;
 ///    }
 ///}
 ///
+///fn main () {
 ///
-///pub fn main(){
+///    let mut actor = MyActorLive::new(1);
 ///
-///    let mut group = AaBbGroupLive::new();
-///    
-///    // access to group method
-///    group.add(1);
-///    assert_eq!((1,1),group.get_value());
+///    actor.set(5);
+///    assert_eq!(actor.get(), (5,0));
 ///
-///    // access to field `a` method
-///    group.a.add(10);
-///    assert_eq!((11,1),group.get_value());
+///    actor.crash();
 ///
-///    // access to field `b` method
-///    group.b.add(100);
-///    assert_eq!((11,101),group.get_value());
+///    // a new instance created with `MyActor::new(1)`
+///    assert_eq!(actor.get(), (1,1));
 ///}
-///
 ///```
+/// 
+/// # shutdown
+/// 
+/// Normally the `play` loop ends when every `live` instance 
+/// is dropped. The `shutdown` option adds a control variant 
+/// `InterShutdown` to the `script` enum and two methods to `live`:
+/// 
+/// - `inter_shutdown(&self)` - asks the actor to stop. Messages sent 
+/// before are handled, later ones are not.
+/// - `inter_join(self)`      - asks the actor to stop and waits until 
+/// the `play` loop has ended. Returns immediately if the actor has 
+/// already stopped.
+/// 
+/// Both methods are blocking for `lib = "std"` and `async` for the rest.
+/// They carry the `inter_` prefix of the other generated methods 
+/// instead of plain `shutdown` and `join`, so they never collide with 
+/// the actor's own methods (a `join` method is common).
+/// 
+/// The messages queued after the shutdown message are dropped unhandled
+/// when the `play` loop ends. A caller waiting for the reply of one of 
+/// them panics, or gets a `ReplyDropped` error with [`fallible`](#fallible), 
+/// and the later sends fail as the channel is closed.
+/// 
+/// `on_stop` names a method `fn(&mut self)` that is called on the actor's 
+/// thread (task) when the `play` loop ends, whether by `shutdown` or because 
+/// all `live` instances were dropped, the same as 
+/// [`hooks(stop = ..)`](#hooks), only one of them can be given. Keep it 
+/// private so it's not included in the `live` methods, for async 
+/// libraries it may be `async`.
+/// 
+/// ## Examples
+///```rust
+///use std::sync::{Arc,Mutex};
 ///
-/// Behind the scenes, the macro will generate some additional types 
-/// very similar to `actor`'s types, for `group-actor` 
-/// `NameScriptGroup` and  `NameLiveGroup`:
+///pub struct MyActor{ value: u8, saved: Arc<Mutex<u8>> }
 ///
-///- `Aa` -       `AaScriptGroup`, `AaLiveGroup`
-///- `Bb` -       `BbScriptGroup`, `BbLiveGroup`
+///#[interthread::actor( shutdown(on_stop = save) )] 
+///impl MyActor {
 ///
-/// For `main-actor` itself: `NameGroupScript` and  `NameGroupLive`:
-///- `AaBb` -     `AaBbGroupScript`, `AaBbGroupLive` 
+///    pub fn new(saved: Arc<Mutex<u8>>) -> Self { Self{ value: 0, saved } } 
 ///
-/// In the context of the `SDPL` framework, `group-actors` are designated as `SDL`
-/// (Script, Direct, Live) and will share the `play` method with the `main-actor`,
-/// which is full `SDPL`.
-/// The following is a type schema of the `group` model in relation 
-/// to the above example:
-///  
-/// ```rust
+///    pub fn set(&mut self, v: u8){
+///        self.value = v;
+///    }
+///    fn save(&mut self){
+///        *self.saved.lock().unwrap() = self.value;
+///    }
+///}
+///
+///fn main () {
+///
+///    let saved = Arc::new(Mutex::new(0));
+///    let mut actor = MyActorLive::new(saved.clone());
+///    let _other = actor.clone();
+///
+///    actor.set(5);
+///    // `_other` is still alive 
+///    actor.inter_join();
+///
+///    assert_eq!(*saved.lock().unwrap(), 5);
+///}
+///```
 /// 
-/// struct Aa;
-/// struct Bb;
+/// # handle
 /// 
-/// enum AaScriptGroup;
-/// struct AaLiveGroup;
+/// By default the handle returned by the spawn function is discarded 
+/// (`smol` tasks are detached). The `handle` option keeps it in the 
+/// `live` instance, shared between clones as a field 
 /// 
-/// enum BbScriptGroup;
-/// struct BbLiveGroup;
+/// `pub handle: Arc<Mutex<Option<HandleType>>>`
 /// 
-/// struct AaBb {
-///     pub a: Aa,
-///     pub b: Bb,
-/// }
+/// where `HandleType` is:
 /// 
-/// enum AaBbGroupScript;
-/// struct AaBbGroupLive {
-///     pub a: AaLiveGroup,
-///     pub b: BbLiveGroup,
-/// }
+/// - `std::thread::JoinHandle<()>`      for `lib = "std"`
+/// - `tokio::task::JoinHandle<()>`      for `lib = "tokio"`
+/// - `async_std::task::JoinHandle<()>`  for `lib = "async_std"`
+/// - `smol::Task<()>`                   for `lib = "smol"`
 /// 
-/// ```
-///
-/// To view all the generated code by `group`, you can either utilize 
-/// the [`example`](attr.example.html) macro or employ the 
-/// [`edit`](attr.actor.html#edit) option within the `group` macro. 
-/// For a convenient shortcut to see the full example using `edit`,
+/// The method `inter_take_handle(&self) -> Option<HandleType>` takes 
+/// the handle out, only the first call returns `Some`.
+/// Joining (awaiting) the handle waits for the actor to end 
+/// and returns the panic payload (error) if the actor has panicked.
+/// 
+/// Note that a `smol::Task` is cancelled when dropped. For `lib = "smol"` 
+/// the `Option` is wrapped in a generated `{Name}Task`, which derefs to it 
+/// and detaches the task if the handle was never taken, so the actor 
+/// handles the queued messages after the last `live` instance is dropped, 
+/// as it does without `handle`. Once taken, dropping the task cancels 
+/// the actor, the remaining calls fail as the channel is closed. 
+/// Call `Task::detach` on it to keep the actor running without awaiting it.
+/// 
+/// The option can not be combined with `debut(legend)`.
+/// 
+/// ## Examples
+///```rust
+///pub struct MyActor;
+///
+///#[interthread::actor( handle )] 
+///impl MyActor {
+///
+///    pub fn new() -> Self { Self } 
+///
+///    pub fn crash(&self){
+///        panic!("crash");
+///    }
+///}
+///
+///fn main () {
+///
+///    let actor = MyActorLive::new();
+///    actor.crash();
+///
+///    let handle = actor.inter_take_handle().unwrap();
+///    assert!(handle.join().is_err());
+///}
+///```
+/// 
+/// # try_send
+/// 
+/// With a bounded channel (`channel = n`) the `live` methods 
+/// block (`std`) or suspend (async libraries) while the channel is full.
+/// The `try_send` option additionally generates a `try_` method for 
+/// every `live` method, it sends the message with `try_send`, 
+/// returning an error instead of waiting for room in the channel, 
+/// so a caller can shed load when the actor is busy.
+/// 
+/// The methods return a `Result` with the error type described in 
+/// [`fallible`](#fallible), extended with the variant:
+/// 
+/// - `Full` - the actor's channel is full.
+/// 
+/// The `try_` methods of the methods without a return type 
+/// return once the message is sent, they are not `async` for the 
+/// async libraries either. The methods that return a value wait 
+/// (`async` for the async libraries) for the reply once the message 
+/// is sent, a dropped reply is `ReplyDropped` and, with 
+/// [`timeout`](#timeout), an expired one is `Timeout`.
+/// The option requires `channel = n`.
+/// 
+/// ## Examples
+///```rust
+///pub struct MyActor(u8);
+///
+///#[interthread::actor( channel = 1, try_send )] 
+///impl MyActor {
+///
+///    pub fn new() -> Self { Self(0) } 
+///
+///    pub fn set(&mut self, v: u8){
+///        self.0 = v;
+///    }
+///    pub fn get(&self) -> u8 {
+///        self.0
+///    }
+///}
+///
+///fn main () {
+///
+///    let mut actor = MyActorLive::new();
+///
+///    // the regular methods are still there 
+///    actor.set(5);
+///    assert_eq!(actor.get(), 5);
+///
+///    match actor.try_set(6) {
+///        Ok(()) => (),
+///        Err(MyActorError::Full) => println!("busy"),
+///        Err(e) => panic!("{e}"),
+///    }
+///}
+///```
+/// 
+/// # timeout
+/// 
+/// The `live` methods that return a type wait for the reply 
+/// for as long as it takes. The `timeout` option limits the waiting time, 
+/// either for all of them `timeout = "1s"` or with a default and 
+/// per method overrides `timeout(default = "1s", method_name = "100ms")`. 
+/// Without `default` only the listed methods are timed.
+/// 
+/// A duration is an integer followed by a unit: 
+/// `"ns"`, `"us"`, `"ms"`, `"s"`, `"m"` or `"h"`, greater than zero 
+/// and no longer than about 584 years.
+/// 
+/// The timed methods return a `Result` with the error type described in 
+/// [`fallible`](#fallible), extended with the variant:
+/// 
+/// - `Timeout` - the actor did not reply in time.
+/// 
+/// They do so whether the `fallible` option is set or not, a timed 
+/// method can fail by nature. As the `default` key sets the default 
+/// duration, a method named `default` is listed as `r#default = "100ms"`.
+/// 
+/// The actor is not interrupted, it completes the call and 
+/// the reply is discarded.
+/// 
+/// | lib         | implementation                               |
+/// |-------------|----------------------------------------------|
+/// | `std`       | `oneshot::Receiver::recv_timeout`            |
+/// | `tokio`     | `tokio::time::timeout` (requires `time` feature) |
+/// | `async_std` | `async_std::future::timeout`                 |
+/// | `smol`      | `smol::future::or` racing a `smol::Timer`    |
+/// 
+/// ## Examples
+///```rust
+///pub struct MyActor;
+///
+///#[interthread::actor( timeout(default = "50ms", slow = "5s") )] 
+///impl MyActor {
+///
+///    pub fn new() -> Self { Self } 
+///
+///    pub fn fast(&self, ms: u64) -> u64 {
+///        std::thread::sleep(std::time::Duration::from_millis(ms));
+///        ms
+///    }
+///    pub fn slow(&self, ms: u64) -> u64 {
+///        std::thread::sleep(std::time::Duration::from_millis(ms));
+///        ms
+///    }
+///}
+///
+///fn main () {
+///
+///    let actor = MyActorLive::new();
+///
+///    assert_eq!(actor.fast(500), Err(MyActorError::Timeout));
+///    assert_eq!(actor.slow(100), Ok(100));
+///}
+///```
+/// A zero or too long duration fails to compile:
+///```compile_fail
+///pub struct MyActor;
+///#[interthread::actor( timeout = "0ms" )] 
+///impl MyActor {
+///    pub fn new() -> Self { Self } 
+///    pub fn value(&self) -> u8 { 0 }
+///}
+///```
+///```compile_fail
+///pub struct MyActor;
+///#[interthread::actor( timeout(default = "1s", value = "6000000h") )] 
+///impl MyActor {
+///    pub fn new() -> Self { Self } 
+///    pub fn value(&self) -> u8 { 0 }
+///}
+///```
+/// 
+/// # cast
+/// 
+/// A `live` method that returns a type allocates a `oneshot` channel 
+/// and waits for the reply, even when the caller has no use for it.
+/// The `cast` option additionally generates a `cast_` method which 
+/// sends the call without a reply channel and returns as soon as 
+/// the message is sent, the result is dropped on the actor side.
+/// 
+/// `cast` applies to every method that returns a type, 
+/// `cast(method_a, method_b)` only to the listed ones.
+/// 
+/// With [`fallible`](#fallible) the `cast_` methods return `Result<(), {Name}Error>`.
+/// 
+/// ## Examples
+///```rust
+///pub struct MyActor(Vec<String>);
+///
+///#[interthread::actor( cast(log) )] 
+///impl MyActor {
+///
+///    pub fn new() -> Self { Self(vec![]) } 
+///
+///    pub fn log(&mut self, line: String) -> usize {
+///        self.0.push(line);
+///        self.0.len()
+///    }
+///}
+///
+///fn main () {
+///
+///    let mut actor = MyActorLive::new();
+///
+///    // no reply channel, no waiting
+///    actor.cast_log("first".to_string());
+///
+///    assert_eq!(actor.log("second".to_string()), 2);
+///}
+///```
+/// 
+/// # deferred
+/// 
+/// With an async library a `live` method has to be awaited 
+/// before the message is even sent. The `deferred` option additionally 
+/// generates a `_deferred` method, a plain `fn` which sends the message 
+/// at call time and returns 
+/// `impl Future<Output = T> + Send + 'static` awaiting the reply only.
+/// The requests are queued in call order and can be 
+/// pipelined or spawned before any of them is awaited.
+/// 
+/// `deferred` applies to every method that returns a type, 
+/// `deferred(method_a, method_b)` only to the listed ones.
+/// The option requires an async library. 
+/// 
+/// With a bounded channel (`channel = n`) the message can not be sent 
+/// without waiting for room in the channel, so the `_deferred` method 
+/// is an `async fn` which sends the message and resolves to the future 
+/// of the reply: `actor.method_deferred(v).await.await`.
+/// 
+/// Combined with [`fallible`](#fallible) or [`timeout`](#timeout) 
+/// the future resolves to `Result<T, {Name}Error>`.
+/// 
+/// ## Examples
+///```rust
+///pub struct MyActor(u32);
+///
+///#[interthread::actor( lib = "tokio", deferred )] 
+///impl MyActor {
+///
+///    pub fn new() -> Self { Self(0) } 
+///
+///    pub fn add(&mut self, v: u32) -> u32 {
+///        self.0 += v;
+///        self.0
+///    }
+///}
+///
+///#[tokio::main]
+///async fn main () {
+///
+///    let mut actor = MyActorLive::new();
+///
+///    // both messages are sent here
+///    let first  = actor.add_deferred(1);
+///    let second = actor.add_deferred(2);
+///
+///    assert_eq!(second.await, 3);
+///    assert_eq!(first.await, 1);
+///}
+///```
+/// 
+/// # with
+/// 
+/// The `with` option generates two `live` methods running a closure 
+/// on the actor, handy for one-off queries and test assertions 
+/// without adding a method to the impl block:
+/// 
+/// - `with(&self, f: impl FnOnce(&Actor) -> R)` 
+/// - `with_mut(&mut self, f: impl FnOnce(&mut Actor) -> R)` 
+/// 
+/// The closure is boxed into a `Script` variant and 
+/// the result `R` is sent back through a `oneshot` channel, 
+/// so both the closure and `R` have to be `Send + 'static`. 
+/// With [`fallible`](#fallible) the methods return `Result<R, {Name}Error>`.
+/// 
+/// ## Examples
+///```rust
+///pub struct MyActor {
+///    value: i8,
+///}
+///
+///#[interthread::actor( with )] 
+///impl MyActor {
+///
+///    pub fn new() -> Self { Self{ value: 0 } } 
+///
+///    pub fn increment(&mut self) {
+///        self.value += 1;
+///    }
+///}
+///
+///fn main () {
+///
+///    let mut actor = MyActorLive::new();
+///    actor.increment();
+///
+///    assert_eq!(actor.with(|a| a.value), 1);
+///
+///    actor.with_mut(|a| a.value = 10);
+///    assert_eq!(actor.with(|a| a.value), 10);
+///}
+///```
+/// 
+/// # priority
+/// 
+/// All the messages are received in order from a single channel, 
+/// so an urgent call waits behind every message queued before it.
+/// The `priority(high(method_a, method_b))` option sends the listed methods 
+/// through a second, unbounded channel which the `play` loop checks 
+/// before receiving every message from the main one.
+/// 
+/// A call of a high priority method is followed by a wake message 
+/// on the main channel, sent without waiting, so that a `play` loop 
+/// waiting on the main channel picks up the call. 
+/// If the main channel is full the actor is busy and the wake message is dropped.
+/// 
+/// The option can not be combined with `debut(legend)`.
+/// 
+/// ## Examples
+///```rust
+///use std::sync::{Arc,Barrier};
+///
+///pub struct MyActor(Vec<String>, Arc<Barrier>);
+///
+///#[interthread::actor( priority(high(cancel)) )] 
+///impl MyActor {
+///
+///    pub fn new(barrier: Arc<Barrier>) -> Self { Self(vec![],barrier) } 
+///
+///    pub fn push(&mut self, s: &'static str){
+///        self.0.push(s.to_string());
+///    }
+///    pub fn cancel(&mut self){
+///        self.0.push("cancel".to_string());
+///    }
+///    pub fn log(&self) -> Vec<String> {
+///        self.0.clone()
+///    }
+///    pub fn pause(&self){
+///        self.1.wait();
+///        self.1.wait();
+///    }
+///}
+///
+///fn main () {
+///
+///    let barrier = Arc::new(Barrier::new(2));
+///    let mut actor = MyActorLive::new(barrier.clone());
+///
+///    // keep the actor busy
+///    actor.pause();
+///    barrier.wait();
+///
+///    actor.push("a");
+///    actor.push("b");
+///    actor.cancel();
+///
+///    barrier.wait();
+///    assert_eq!(actor.log(), vec!["cancel","a","b"]);
+///}
+///```
+/// 
+/// # tick
+/// 
+/// The `tick(every = "250ms", method = on_tick)` option makes the `play` 
+/// loop wake up on a timer and call the named `fn(&mut self)` method 
+/// of the actor between messages, keeping periodic housekeeping 
+/// inside the actor without an extra thread or a message round trip.
+/// The method may be private and, for async libraries, `async`.
+/// 
+/// A tick is due every time the period elapses, 
+/// the ticks missed while the actor was busy are skipped.
+/// A duration is an integer followed by a unit: 
+/// `"ns"`, `"us"`, `"ms"`, `"s"`, `"m"` or `"h"`.
+/// 
+/// | lib         | implementation                              |
+/// |-------------|---------------------------------------------|
+/// | `std`       | `Receiver::recv_timeout`                    |
+/// | `tokio`     | `tokio::time::timeout_at` (requires `time` feature) |
+/// | `async_std` | `async_std::future::timeout`                |
+/// | `smol`      | `smol::future::or` racing a `smol::Timer`   |
+/// 
+/// ## Examples
+///```rust
+///pub struct MyActor(usize);
+///
+///#[interthread::actor( tick(every = "10ms", method = on_tick) )] 
+///impl MyActor {
+///
+///    pub fn new() -> Self { Self(0) } 
+///
+///    pub fn ticks(&self) -> usize {
+///        self.0
+///    }
+///    fn on_tick(&mut self){
+///        self.0 += 1;
+///    }
+///}
+///
+///fn main () {
+///
+///    let actor = MyActorLive::new();
+///
+///    std::thread::sleep(std::time::Duration::from_millis(100));
+///    assert!(actor.ticks() > 0);
+///}
+///```
+/// 
+/// # hooks
+/// 
+/// The `new` method runs on the caller's thread, so resources 
+/// that have to live on the actor's thread (task) can not be created there.
+/// The `hooks` option names methods of the actor called by the `play` loop:
+/// 
+/// | hook     | signature                      | called                                  |
+/// |----------|--------------------------------|-----------------------------------------|
+/// | `start`  | `fn(&mut self)`                | before the first message                |
+/// | `stop`   | `fn(&mut self)`                | after the last message                  |
+/// | `before` | `fn(&mut self, &'static str)`  | before every message                    |
+/// | `after`  | `fn(&mut self, &'static str)`  | after every message                     |
+/// 
+/// `before` and `after` receive the name of the `Script` variant 
+/// of the message, the variants added internally by other options 
+/// are not observed. The methods may be private and, for async 
+/// libraries, `async`. When combined with `supervise`, `start` is 
+/// called again on a rebuilt actor, before `on_restart`.
+/// `stop` can not be combined with [`shutdown(on_stop = ..)`](#shutdown).
+/// 
+/// ## Examples
+///```rust
+///use std::sync::{Arc,Mutex};
+///
+///pub struct MyActor {
+///    log: Arc<Mutex<Vec<String>>>,
+///}
+///
+///#[interthread::actor( hooks(start = on_start, stop = on_stop, before = before), shutdown )] 
+///impl MyActor {
+///
+///    pub fn new(log: Arc<Mutex<Vec<String>>>) -> Self { Self{ log } } 
+///
+///    pub fn input(&mut self, _v: i8) {}
+///
+///    fn on_start(&mut self){
+///        self.log.lock().unwrap().push("start".into());
+///    }
+///    fn on_stop(&mut self){
+///        self.log.lock().unwrap().push("stop".into());
+///    }
+///    fn before(&mut self, variant: &'static str){
+///        self.log.lock().unwrap().push(variant.into());
+///    }
+///}
+///
+///fn main () {
+///
+///    let log = Arc::new(Mutex::new(Vec::new()));
+///    let mut actor = MyActorLive::new(log.clone());
+///
+///    actor.input(3);
+///    actor.inter_join();
+///
+///    assert_eq!(*log.lock().unwrap(), vec!["start","Input","stop"]);
+///}
+///```
+/// 
+/// # pool
+/// 
+/// The `pool(size = 4)` option spawns `size` instances of the actor, 
+/// each one created by its own call of `new` (or `try_new`) with 
+/// cloned arguments, behind a single cloneable live instance.
+/// Every message is sent to one of the instances as chosen by `dispatch`:
+/// 
+/// | dispatch          | instance                                                    |
+/// |-------------------|-------------------------------------------------------------|
+/// | `"round_robin"` * | the next one, the counter is shared between clones          |
+/// | `"least_queued"`  | the one with the fewest messages queued or in progress      |
+/// | `"key_hash"`      | the hash of the argument named by `key`                     |
+/// 
+/// With `"key_hash"` the messages of the same key are always 
+/// handled by the same instance, in order. The `key` argument has 
+/// to implement `Hash`, the methods without such an argument 
+/// are dispatched round robin.
+/// 
+/// The option can not be combined with `handle`, `shutdown`, 
+/// `priority`, `supervise`, `interact`, `try_send`, `tick`, `with`, 
+/// `deferred` or `debut(legend)`, as these address a single 
+/// instance of the actor.
+/// 
+/// ## Examples
+///```rust
+///use std::collections::HashMap;
+///
+///pub struct MyActor(HashMap<String,u32>);
+///
+///#[interthread::actor( pool(size = 4, dispatch = "key_hash", key = name) )] 
+///impl MyActor {
+///
+///    pub fn new() -> Self { Self(HashMap::new()) } 
+///
+///    pub fn add(&mut self, name: String, v: u32) -> u32 {
+///        let count = self.0.entry(name).or_default();
+///        *count += v;
+///        *count
+///    }
+///}
+///
+///fn main () {
+///
+///    let mut actor = MyActorLive::new();
+///    for name in ["a","b","c"] {
+///        actor.add(name.to_string(), 1);
+///        assert_eq!(actor.add(name.to_string(), 1), 2);
+///    }
+///}
+///```
+/// The rejected combinations fail to compile:
+///```compile_fail
+///pub struct MyActor;
+///#[interthread::actor( pool(size = 2), channel = 2, try_send )] 
+///impl MyActor {
+///    pub fn new() -> Self { Self } 
+///}
+///```
+///```compile_fail
+///pub struct MyActor;
+///#[interthread::actor( pool(size = 2), tick(every = "1s", method = on_tick) )] 
+///impl MyActor {
+///    pub fn new() -> Self { Self } 
+///    fn on_tick(&mut self) {}
+///}
+///```
+///```compile_fail
+///pub struct MyActor;
+///#[interthread::actor( pool(size = 2), with )] 
+///impl MyActor {
+///    pub fn new() -> Self { Self } 
+///}
+///```
+///```compile_fail
+///pub struct MyActor;
+///#[interthread::actor( lib = "tokio", pool(size = 2), deferred )] 
+///impl MyActor {
+///    pub fn new() -> Self { Self } 
+///    pub fn value(&self) -> u8 { 0 }
+///}
+///```
+/// 
+/// # events
+/// 
+/// An actor only answers calls. The `events(Type)` option lets it 
+/// push notifications of a `Clone` type to any number of subscribers.
+/// It relies on the [`interact`](#interact) variables scheme: an 
+/// actor method declaring an `inter_emit: impl Fn(Type)` argument 
+/// receives the emitter, which the `live` method gets from the 
+/// generated getter `inter_get_emit`. The `live` instance gets a
+/// `subscribe` method returning a new receiver of the events 
+/// emitted from then on.
+/// 
+/// | lib                  | channel                                 |
+/// |----------------------|-----------------------------------------|
+/// | `std`                | a `std::sync::mpsc` channel per subscriber |
+/// | `tokio`              | `tokio::sync::broadcast`                |
+/// | `async_std`, `smol`  | `async_broadcast` (requires the `async-broadcast` crate) |
+/// 
+/// `capacity` (64 by default) bounds the `tokio` and `async_broadcast` 
+/// channels, a slow subscriber loses the oldest events and gets 
+/// a lag (overflow) error. The `std` channels are unbounded.
+/// 
+/// ## Examples
+///```rust
+///#[derive(Debug,Clone,PartialEq)]
+///pub enum Event {
+///    Changed(u8),
+///}
+///
+///pub struct MyActor(u8);
+///
+///#[interthread::actor( interact, events(Event) )] 
+///impl MyActor {
+///
+///    pub fn new() -> Self { Self(0) } 
+///
+///    pub fn set(&mut self, v: u8, inter_emit: impl Fn(Event)) {
+///        self.0 = v;
+///        inter_emit(Event::Changed(v));
+///    }
+///}
+///
+///fn main () {
+///
+///    let mut actor = MyActorLive::new();
+///    let events = actor.subscribe();
+///
+///    // note the signature, `inter_emit` is provided by the model
+///    actor.set(3);
+///
+///    assert_eq!(events.recv(), Ok(Event::Changed(3)));
+///}
+///```
+/// 
+/// # weak
+/// 
+/// The `play` loop ends once every `live` instance is dropped, 
+/// so a handle kept by a cache, an observer or another actor 
+/// keeps the actor alive. The `weak` option generates a
+/// `{ActorName}WeakLive` type, returned by the `live` method 
+/// `downgrade`, which does not keep the actor alive. Its method 
+/// `upgrade` returns `Some` live instance as long as the actor is 
+/// alive and `None` afterwards.
+/// 
+/// With `lib = "tokio"` the weak type holds `tokio` weak senders. 
+/// Other libraries have no weak senders, the `live` instances 
+/// share a sender through an `Arc` (field `inter_alive`), the 
+/// weak instances hold a `std::sync::Weak` to it.
+/// 
+/// The option can not be combined with `debut(legend)`.
+/// 
+/// ## Examples
+///```rust
+///pub struct Node {
+///    peer: Option<NodeWeakLive>,
+///}
+///
+///#[interthread::actor( weak, handle )] 
+///impl Node {
+///
+///    pub fn new() -> Self { Self{ peer: None } } 
+///
+///    pub fn set_peer(&mut self, peer: NodeWeakLive) {
+///        self.peer = Some(peer);
+///    }
+///}
+///
+///fn main () {
+///
+///    let mut a = NodeLive::new();
+///    let mut b = NodeLive::new();
+///
+///    // the actors refer to each other
+///    a.set_peer(b.downgrade());
+///    b.set_peer(a.downgrade());
+///
+///    let weak   = a.downgrade();
+///    let handle = a.inter_take_handle().unwrap();
+///    assert!(weak.upgrade().is_some());
+///
+///    // no reference cycle, the actor ends
+///    drop(a);
+///    handle.join().unwrap();
+///    assert!(weak.upgrade().is_none());
+///}
+///```
+/// 
+/// # registry
+/// 
+/// The `registry` option keeps a per-type collection of running 
+/// actors, so a `live` instance can be found by name without passing
+/// it around. The `live` instance gets the methods:
+/// 
+/// - `register(&self, name)` registers the instance under the name, 
+///   returning the instance previously registered under it, if any
+/// - `lookup(name)` returns a clone of the registered instance
+/// - `registered()` returns the registered names
+/// - `deregister(name)` removes and returns the registered instance
+/// 
+/// The collection holds strong `live` instances, `register` pins 
+/// the actor: dropping every other `live` instance does not end it,
+/// it stays alive until it is deregistered under every name or it 
+/// stops by itself (`shutdown`, panic). A stopped actor is 
+/// deregistered under every name it was registered with.
+/// 
+/// The option is not supported for generic actors and can not be 
+/// combined with `debut(legend)`.
+/// 
+/// ## Examples
+///```rust
+///pub struct Service(u32);
+///
+///#[interthread::actor( registry )] 
+///impl Service {
+///
+///    pub fn new() -> Self { Self(0) } 
+///
+///    pub fn call(&mut self) -> u32 {
+///        self.0 += 1;
+///        self.0
+///    }
+///}
+///
+///fn plugin() -> u32 {
+///    // no handle passed around
+///    ServiceLive::lookup("service").unwrap().call()
+///}
+///
+///fn main () {
+///
+///    ServiceLive::new().register("service");
+///
+///    assert_eq!(plugin(), 1);
+///    assert_eq!(plugin(), 2);
+///    assert_eq!(ServiceLive::registered(), vec!["service"]);
+///
+///    // the actor stops once deregistered 
+///    let service = ServiceLive::deregister("service");
+///    assert!(service.is_some());
+///    assert!(ServiceLive::lookup("service").is_none());
+///}
+///```
+/// 
+/// # metrics
+/// 
+/// The `metrics` option makes the model track its mailbox and 
+/// the time spent on every message. The `live` method `metrics`
+/// returns a snapshot of the generated type `{ActorName}Metrics`:
+/// 
+/// | field         | type                                     |                                    |
+/// |---------------|------------------------------------------|------------------------------------|
+/// | `queue_depth` | `usize`                                  | messages sent and not yet received |
+/// | `processed`   | `BTreeMap<&'static str, u64>`            | messages processed per `Script` variant |
+/// | `elapsed`     | `BTreeMap<&'static str, Duration>`       | total processing time per `Script` variant |
+/// 
+/// A message is counted once its method returns, the reply of a 
+/// method may arrive before the message is counted. The metrics 
+/// of a `pool` are shared by its instances.
+/// 
+/// The option can not be combined with `priority` or `debut(legend)`.
+/// 
+/// ## Examples
+///```rust
+///pub struct MyActor;
+///
+///#[interthread::actor( metrics )] 
+///impl MyActor {
+///
+///    pub fn new() -> Self { Self } 
+///
+///    pub fn work(&self) {
+///        std::thread::sleep(std::time::Duration::from_millis(10));
+///    }
+///    pub fn ping(&self) -> bool { true }
+///}
+///
+///fn main () {
+///
+///    let actor = MyActorLive::new();
+///    actor.work();
+///    actor.work();
+///    assert!(actor.ping());
+///
+///    let metrics = actor.metrics();
+///    assert_eq!(metrics.queue_depth, 0);
+///    assert_eq!(metrics.processed["Work"], 2);
+///
+///    let average = metrics.elapsed["Work"] / 2;
+///    assert!(average >= std::time::Duration::from_millis(10));
+///}
+///```
+/// 
+/// # trace
+/// 
+/// The `trace` option instruments the model with the
+/// [`tracing`](https://docs.rs/tracing) crate, which has to be
+/// a dependency of the user crate. 
+/// 
+/// Every message is handled inside an `info` span named 
+/// `"{ActorName}::{method}"`, a child of the span that was current
+/// when the `live` method was called. When `debut` is enabled the
+/// span records the `debut` of the instance as well.
+/// 
+/// The `live` methods record two `debug` events, `"enqueue"` when 
+/// the message is sent (with the message as `msg` field and, for 
+/// `debut`, the `name` of the instance) and `"reply"` once the 
+/// reply is received.
+/// 
+/// ## Examples
+///```rust
+///pub struct MyActor(u8);
+///
+///#[interthread::actor( trace )] 
+///impl MyActor {
+///
+///    pub fn new() -> Self { Self(0) } 
+///
+///    pub fn add(&mut self, v: u8) -> u8 {
+///        // runs inside the "MyActor::add" span
+///        tracing::info!("adding {v}");
+///        self.0 += v;
+///        self.0
+///    }
+///}
+///
+///fn main () {
+///
+///    let mut actor = MyActorLive::new();
+///
+///    // the span of the message is a child of "request"
+///    let value = tracing::info_span!("request").in_scope(|| actor.add(1));
+///    assert_eq!(value, 1);
+///}
+///```
+/// 
+/// # on_end
+/// 
+/// By default the `play` loop prints `"{ActorName} the end ..."` 
+/// to stderr when the actor stops. The `on_end` argument selects 
+/// how the end of the actor is reported:
+/// 
+/// | value           |                                                          |
+/// |-----------------|----------------------------------------------------------|
+/// | `"stderr"` *    | the `eprintln!` line                                     |
+/// | `"silent"`      | nothing is reported                                      |
+/// | `"log"`         | `log::info!` on a normal end, `log::error!` on a panic   |
+/// | `"tracing"`     | `tracing::info!` on a normal end, `tracing::error!` on a panic |
+/// | `function_name` | a call to a user function                                |
+/// 
+/// The `"log"` and `"tracing"` values require the respective crate 
+/// as a dependency of the user crate. Except for `"stderr"`, a panic
+/// of the actor is reported as well.
+/// 
+/// The user function has the signature 
+/// `fn(&'static str, Option<std::time::SystemTime>, {ActorName}End)`,
+/// it receives the name of the actor, the `debut` of the instance 
+/// (if `debut` is enabled) and the exit reason, the generated enum 
+/// `{ActorName}End` with the variants `Closed` and `Panicked`.
+/// 
+/// ## Examples
+///```rust
+///use std::sync::atomic::{AtomicBool,Ordering};
+///
+///static PANICKED: AtomicBool = AtomicBool::new(false);
+///
+///fn on_end(name: &'static str, _debut: Option<std::time::SystemTime>, reason: MyActorEnd) {
+///    assert_eq!(name, "MyActor");
+///    PANICKED.store(reason == MyActorEnd::Panicked, Ordering::SeqCst);
+///}
+///
+///pub struct MyActor;
+///
+///#[interthread::actor( on_end = on_end, handle )] 
+///impl MyActor {
+///
+///    pub fn new() -> Self { Self } 
+///
+///    pub fn crash(&self) { panic!("crash") }
+///}
+///
+///fn main () {
+///
+///    let actor = MyActorLive::new();
+///    let handle = actor.inter_take_handle().unwrap();
+///    actor.crash();
+///
+///    assert!(handle.join().is_err());
+///    assert!(PANICKED.load(Ordering::SeqCst));
+///}
+///```
+/// 
+/// # serde
+/// 
+/// The `serde` option derives `serde::Serialize` and `serde::Deserialize`
+/// for the `Script` enum, the user crate depends on `serde` (with the 
+/// `derive` feature) and `serde_json`. The arguments and return types 
+/// of the actor methods have to be serializable as well. The oneshot 
+/// senders of the replies are not serialized, a new one is created 
+/// on deserialization.
+/// 
+/// With `serde(journal = "file_name.jsonl")` the `play` loop appends 
+/// every message it handles to the journal file, one JSON object per 
+/// line, before handling it. A journal file that can not be opened 
+/// is reported once on `stderr`, the actor runs without a journal.
+/// 
+/// The generated `Script::replay(path, &mut actor)` reads a journal 
+/// and hands its messages, in order, to an actor object. The replies 
+/// are dropped. Replaying the journal of a failing actor against 
+/// a fresh one reproduces the exact sequence of messages it received.
+/// 
+/// The option is not supported for generic actors and can not be 
+/// combined with `with`, `trace` or `interact`.
+/// 
+/// ## Examples
+///```rust
+///pub struct MyActor(Vec<u32>);
+///
+///#[interthread::actor( serde )] 
+///impl MyActor {
+///
+///    pub fn new() -> Self { Self(vec![]) } 
+///
+///    pub fn push(&mut self, value: u32) { self.0.push(value) }
+///
+///    pub fn sum(&self) -> u32 { self.0.iter().sum() }
+///}
+///
+///fn main () {
+///
+///    let msg = MyActorScript::Push{ input: 7 };
+///    let line = serde_json::to_string(&msg).unwrap();
+///    assert_eq!(line, r#"{"Push":{"input":7}}"#);
+///
+///    let journal = std::env::temp_dir().join("my_actor_journal.jsonl");
+///    std::fs::write(&journal, format!("{line}\n{line}\n")).unwrap();
+///
+///    let mut actor = MyActor::new();
+///    MyActorScript::replay(&journal, &mut actor).unwrap();
+///    assert_eq!(actor.sum(), 14);
+///}
+///```
+/// 
+/// # remote
+/// 
+/// The `remote` option, which requires `serde`, moves an actor 
+/// behind a byte stream. Next to the `live` instance the macro 
+/// generates:
+/// 
+/// - `{ActorName}Remote<S>`, a client exposing the methods of the
+/// actor with the arguments of the `live` methods. Every method
+/// returns a `std::io::Result` of the `live` return type, a 
+/// transport failure is an `Err` rather than a panic. 
+/// The client is cheap to clone, its clones share the stream.
+/// - `{ActorName}Server<S>`, created with a `live` instance and the 
+/// other end of the stream, its method `serve` handles the messages
+/// of a client until the stream is closed.
+/// - `{ActorName}Reply`, the replies of the methods with a return type.
+/// 
+/// A message is a frame, a `u32` (big endian) length followed by
+/// the JSON of the `Script` variant (or of the reply), a frame longer
+/// than 16 MiB is an `InvalidData` error. The methods without a return 
+/// type do not wait for a reply. An error of the `live` instance 
+/// (`fallible`, `timeout`) is sent back as an `Other` error, 
+/// the connection is kept.
+/// 
+/// The stream `S` is any `std::io::Read + std::io::Write` for the `std` 
+/// actors, any `AsyncRead + AsyncWrite + Unpin` of the respective library 
+/// (`tokio::io`, `async_std::io`, `smol::io`) for the async ones, a TCP or
+/// Unix socket or the pipes of a child process.
+/// 
+/// ## Examples
+///```rust
+///pub struct MyActor(u32);
+///
+///#[interthread::actor( serde, remote )] 
+///impl MyActor {
+///
+///    pub fn new() -> Self { Self(0) } 
+///
+///    pub fn add(&mut self, value: u32) -> u32 { 
+///        self.0 += value;
+///        self.0 
+///    }
+///}
+///
+///fn main () {
+///
+///    let (client, server) = std::os::unix::net::UnixStream::pair().unwrap();
+///
+///    // the server side
+///    let live = MyActorLive::new();
+///    std::thread::spawn(move || MyActorServer::new(live, server).serve());
+///
+///    // the client side
+///    let actor = MyActorRemote::new(client);
+///    assert_eq!(actor.add(1).unwrap(), 1);
+///    assert_eq!(actor.add(2).unwrap(), 3);
+///}
+///```
+/// 
+/// 
+/// 
+
+
+#[proc_macro_error::proc_macro_error]
+#[proc_macro_attribute]
+pub fn actor( attr: proc_macro::TokenStream, item: proc_macro::TokenStream ) -> proc_macro::TokenStream {
+    
+    let item_impl = syn::parse_macro_input!(item as syn::ItemImpl);
+
+    let mut aaa = model::attribute::ActorAttributeArguments::default();
+    let nested  = syn::parse_macro_input!(attr with syn::punctuated::Punctuated::<syn::Meta,syn::Token![,]>::parse_terminated); 
+    aaa.parse_nested(nested);
+    aaa.cross_check();
+
+    check::channels_import( &aaa.lib, &aaa.channel_impl );
+    check::options_import( &aaa );
+
+    let edit_attr = aaa.edit.attr.clone();
+
+    let aa = crate::model::AttributeArguments::Actor(aaa);
+
+    let model_sdpl = crate::model::generate_model( aa,&item_impl,None);
+    
+    let (_,edit_sdpl) = model_sdpl.split();
+
+    if let Some( edit_attr ) = edit_attr {
+
+        parse::edit_write( &edit_attr, &item_impl, edit_sdpl);
+    }
+
+    let (code,_) = model_sdpl.get_code_edit();
+
+    quote::quote!{
+        #item_impl
+        #code
+    }.into()
+
+
+}
+
+
+/// ## A set of actors sharing  a single thread
+///
+/// In the realm of concurrent programming, creating a separate thread 
+/// for each individual actor can sometimes incur a significant overhead. 
+/// In such scenarios, developers may opt to populate an `actor`'s 
+/// definition with complex encapsulations (potential actors), 
+/// effectively creating a collection of objects running within 
+/// a single thread. While this approach proves resource-efficient, 
+/// it does come with a trade-off: accessing the methods of field 
+/// types must be explicitly written within the main `actor` 
+/// implementation block.
+/// 
+/// This is where the `group` macro comes into play. A `group` is a 
+/// set of `actors` that share a single thread, consisting of 
+/// a `main-actor` and `group-actors` contained within the 
+/// `main-actor`'s fields. Developers can now bypass the need to rewrite 
+/// method wrappers, gaining direct access to `group-actor` methods via 
+/// dot notation, as seamlessly as if these `group-actors` were `actors` 
+/// in their own right.
+/// 
+/// In this scenario, the methods of the `main-actor` take on the responsibility 
+/// for interaction within and between `group-actors`, while the latter primarily 
+/// serve to export their functionality. 
+/// 
+/// Before delving into further details, let's explore an example of a `group`. 
+/// Assuming there is a good understanding of how an `actor` operates, once the 
+/// `Live` instance is returned after invoking the `new` method, the `actor` is 
+/// already running in a separate thread. Therefore, there’s no need to 
+/// complicate the example with extra thread spawning just for visual clarity. 
+/// 
+/// 
+/// ## Examples
+///  
+///```rust
+///
+///// We have `Aa` and `Bb`
+///pub struct Aa(u8);
+///impl Aa {
+///    pub fn add(&mut self, v: u8){
+///        self.0 += v;
+///    }
+///}
+///
+///pub struct Bb(u8);
+///impl Bb {
+///    pub fn add(&mut self, v: u8){
+///        self.0 += v;
+///    }
+///}
+///
+///// Definition of group
+///pub struct AaBb {
+///    pub a: Aa,
+///    pub b: Bb,
+///}
+///
+///#[interthread::group( file= "path/to/file.rs")]
+///impl AaBb {
+///
+///    pub fn new( ) -> Self {
+///        let a = Aa(0);
+///        let b = Bb(0);
+///        Self{ a,b}
+///    }
+///
+///    pub fn add(&mut self, v:u8){
+///        self.a.0 += v;
+///        self.b.0 += v;
+///    }
+///
+///    pub fn get_value(&mut self) -> (u8,u8) {
+///        (self.a.0,self.b.0)
+///    }
+///}
+///
+///
+///pub fn main(){
+///
+///    let mut group = AaBbGroupLive::new();
+///    
+///    // access to group method
+///    group.add(1);
+///    assert_eq!((1,1),group.get_value());
+///
+///    // access to field `a` method
+///    group.a.add(10);
+///    assert_eq!((11,1),group.get_value());
+///
+///    // access to field `b` method
+///    group.b.add(100);
+///    assert_eq!((11,101),group.get_value());
+///}
+///
+///```
+///
+/// Behind the scenes, the macro will generate some additional types 
+/// very similar to `actor`'s types, for `group-actor` 
+/// `NameScriptGroup` and  `NameLiveGroup`:
+///
+///- `Aa` -       `AaScriptGroup`, `AaLiveGroup`
+///- `Bb` -       `BbScriptGroup`, `BbLiveGroup`
+///
+/// For `main-actor` itself: `NameGroupScript` and  `NameGroupLive`:
+///- `AaBb` -     `AaBbGroupScript`, `AaBbGroupLive` 
+///
+/// In the context of the `SDPL` framework, `group-actors` are designated as `SDL`
+/// (Script, Direct, Live) and will share the `play` method with the `main-actor`,
+/// which is full `SDPL`.
+/// The following is a type schema of the `group` model in relation 
+/// to the above example:
+///  
+/// ```rust
+/// 
+/// struct Aa;
+/// struct Bb;
+/// 
+/// enum AaScriptGroup;
+/// struct AaLiveGroup;
+/// 
+/// enum BbScriptGroup;
+/// struct BbLiveGroup;
+/// 
+/// struct AaBb {
+///     pub a: Aa,
+///     pub b: Bb,
+/// }
+/// 
+/// enum AaBbGroupScript;
+/// struct AaBbGroupLive {
+///     pub a: AaLiveGroup,
+///     pub b: BbLiveGroup,
+/// }
+/// 
+/// ```
+///
+/// To view all the generated code by `group`, you can either utilize 
+/// the [`example`](attr.example.html) macro or employ the 
+/// [`edit`](attr.actor.html#edit) option within the `group` macro. 
+/// For a convenient shortcut to see the full example using `edit`,
 /// simply use `edit(file)`."
 /// 
 /// ```rust
@@ -1580,6 +2971,16 @@ pub fn actor( attr: proc_macro::TokenStream, item: proc_macro::TokenStream ) ->
 ///             legend
 ///             )   
 ///
+/// AA     fallible
+///
+/// AA     handle
+///
+/// AA     try_send
+///
+/// AA     shutdown(
+///             on_stop = method_name
+///             )
+///
 /// (AA)   assoc(
 ///             self::assoc,
 ///             ..
@@ -1622,6 +3023,10 @@ pub fn actor( attr: proc_macro::TokenStream, item: proc_macro::TokenStream ) ->
 /// - [`name`](attr.actor.html#name)
 /// - [`assoc`](attr.actor.html#assoc)
 /// - [`debut`](attr.actor.html#debut)
+/// - [`fallible`](attr.actor.html#fallible)
+/// - [`shutdown`](attr.actor.html#shutdown)
+/// - [`handle`](attr.actor.html#handle)
+/// - [`try_send`](attr.actor.html#try_send)
 /// - [`path`](#path)
 /// - [`allow`](#allow)
 
@@ -1691,7 +3096,7 @@ pub fn group( attr: proc_macro::TokenStream, item: proc_macro::TokenStream ) ->
     gaa.parse_nested(nested);
     gaa.cross_check(&item_impl);
     
-    check::channels_import( &gaa.lib );
+    check::channels_import( &gaa.lib, &model::ChannelImpl::default() );
 
      let edit_attr = gaa.edit.attr.clone();
 
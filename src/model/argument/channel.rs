@@ -1,6 +1,8 @@
-use crate::model::{ Lib,Vars,ActorAttributeArguments};
+use crate::error;
+use crate::model::{ get_lit_str,Lib,Vars,ActorAttributeArguments};
 
-use syn::{ Ident,Type };
+use proc_macro_error::abort;
+use syn::{ Ident,Meta,Type };
 use quote::quote;
 use proc_macro2::TokenStream;
 
@@ -11,6 +13,8 @@ pub struct OneshotChannel {
     send: Ident,
     recv: Ident,
     lib:    Lib,
+    // the actor name when traced
+    pub trace: Option<Ident>,
 }
 
 impl OneshotChannel {
@@ -18,7 +22,8 @@ impl OneshotChannel {
     pub fn new( send: &Ident, recv: &Ident, lib: &Lib ) -> Self {
         Self{ send: send.clone(),
               recv: recv.clone(),
-              lib: lib.clone()  }
+              lib: lib.clone(),
+              trace: None  }
     }
     
     pub fn get_decl(lib: &Lib, ty: Option<&Type>) -> TokenStream {
@@ -65,9 +70,27 @@ impl OneshotChannel {
         let  Self{recv,lib,..} = self;
         let error = format!("'{obj}::{met}' from {recv}. Channel is closed!");
 
-        match lib {
+        let call = match lib {
             Lib::Std =>  quote!{ #recv .recv().unwrap_or_else(|_error| core::panic!( #error ))} ,
                    _ =>  quote!{ #recv .await.unwrap_or_else(|_error| core::panic!( #error ))} ,
+        };
+        self.traced(call)
+    }
+    pub fn recv_call_err(&self, error_name: &Ident) -> TokenStream {
+        let  Self{recv,lib,..} = self;
+        let map_err = quote!{ .map_err(|_error| #error_name :: ReplyDropped) };
+
+        let call = match lib {
+            Lib::Std =>  quote!{ #recv .recv() #map_err } ,
+                   _ =>  quote!{ #recv .await #map_err } ,
+        };
+        self.traced(call)
+    }
+    // records the reply when traced
+    pub fn traced(&self, call: TokenStream) -> TokenStream {
+        match &self.trace {
+            Some(cust_name) => crate::model::trace_reply(cust_name, call),
+            None => call,
         }
     }
     // pub fn send_call(&self,load: TokenStream, obj: &Ident, met: &Ident) -> TokenStream {
@@ -77,6 +100,7 @@ impl OneshotChannel {
     // }
 }
 
+#[derive(Clone)]
 pub struct MpscChannel {
     pub type_sender:       TokenStream,    
     pub type_receiver:     TokenStream,     
@@ -84,31 +108,60 @@ pub struct MpscChannel {
     pub pat_type_receiver: TokenStream,    
     pub declaration:       TokenStream,    
     pub sender_call:       TokenStream,    
+    pub sender_call_err:   TokenStream,    
+    pub sender_send:       TokenStream,    
+    pub sender_try_call:   TokenStream,    
+    pub sender_sync_call:  TokenStream,    
+    pub sender_sync_send:  TokenStream,    
 }
 
 impl MpscChannel {
 
     pub fn new(
-            Vars{
-                sender,
-              receiver,
-             live_name,
-                   msg,..
-            } : &Vars,
+            vars : &Vars,
             ActorAttributeArguments{
                 channel,
-                lib,..
+                channel_impl,
+                lib,
+                pool,
+                metrics,
+                trace,
+                debut,..
             } : &ActorAttributeArguments,
  
            script_type: &Type ) -> Self {
 
+        let Vars{ sender, receiver, live_name, error_name, msg,.. } = vars;
         let error = format!("'{live_name}::method.send'. Channel is closed!");
+        // the message sent
+        let send_msg = 
+        if *trace { crate::model::trace_send(vars, debut.active()) } else { quote!{ #msg } };
+        let send = |call: TokenStream| {
+            let send = 
+            if pool.active(){ pool.live_send(vars, call) } else { quote!{ self.#sender.#call } };
+            if *metrics { crate::model::metrics_send(vars, send) } else { send }
+        };
+        let send_await = send(quote!{ send(#send_msg).await });
+        let send_sync  = send(quote!{ send(#send_msg) });
+        let send_try   = send(quote!{ try_send(#send_msg) });
         let type_sender:       TokenStream;    
         let type_receiver:     TokenStream;
         let pat_type_sender:   TokenStream;    
         let pat_type_receiver: TokenStream;    
         let declaration:       TokenStream;    
-        let mut sender_call = quote!{ let _ = self.#sender.send(msg).await; };
+        let mut sender_call = quote!{ let _ = #send_await; };
+        let map_err = quote!{ .map_err(|_error| #error_name :: SendClosed)?; };
+        let mut sender_call_err = quote!{ #send_await #map_err };
+        let mut sender_send = quote!{ #send_await };
+        let mut sender_try_call = quote!{};
+        let mut sender_sync_call = quote!{};
+        let mut sender_sync_send = quote!{};
+        let try_call = |try_send_error: TokenStream| quote!{ 
+            #send_try.map_err(|error| match error { 
+                #try_send_error :: Full(_) => #error_name :: Full,
+                _ => #error_name :: SendClosed,
+            })?;
+        };
 
         match  channel {
     
@@ -117,12 +170,15 @@ impl MpscChannel {
                match  lib { 
             
                    Lib::Std      => {
-                        type_sender       = quote!{ std::sync::mpsc::Sender<#script_type>};    
-                        type_receiver     = quote!{ std::sync::mpsc::Receiver<#script_type>};
+                        let (sender_ty, receiver_ty, channel_decl) = channel_impl.mailbox(script_type, None);
+                        type_sender       = sender_ty;    
+                        type_receiver     = receiver_ty;
                         pat_type_sender   = quote!{ #sender: #type_sender, };   
                         pat_type_receiver = quote!{ #receiver: #type_receiver, }; 
-                        declaration       = quote!{ let ( #sender, #receiver ) = std::sync::mpsc::channel(); };
-                        sender_call       = quote!{ let _ = self.#sender.send(#msg).expect(#error);};
+                        declaration       = quote!{ let ( #sender, #receiver ) = #channel_decl; };
+                        sender_call       = quote!{ let _ = #send_sync.expect(#error);};
+                        sender_call_err   = quote!{ #send_sync #map_err };
+                        sender_send       = quote!{ #send_sync };
                    },
             
                    Lib::Tokio    => {
@@ -131,7 +187,11 @@ impl MpscChannel {
                         pat_type_sender   = quote!{ #sender: #type_sender, };
                         pat_type_receiver = quote!{ mut #receiver: #type_receiver, }; 
                         declaration       = quote!{ let ( #sender, #receiver ) = tokio::sync::mpsc::unbounded_channel(); };                
-                        sender_call       = quote!{ let _ = self.#sender.send(#msg).expect(#error);};
+                        sender_call       = quote!{ let _ = #send_sync.expect(#error);};
+                        sender_call_err   = quote!{ #send_sync #map_err };
+                        sender_send       = quote!{ #send_sync };
+                        sender_sync_call  = sender_call.clone();
+                        sender_sync_send  = sender_send.clone();
                    },
             
                    Lib::AsyncStd  => {
@@ -140,6 +200,8 @@ impl MpscChannel {
                         pat_type_sender   = quote!{ #sender: #type_sender, };
                         pat_type_receiver = quote!{ #receiver: #type_receiver, };
                         declaration       = quote!{ let ( #sender, #receiver ) = async_std::channel::unbounded(); };                    
+                        sender_sync_call  = quote!{ let _ = #send_try.expect(#error);};
+                        sender_sync_send  = quote!{ #send_try };
                    },
             
                    Lib::Smol      => {
@@ -148,6 +210,8 @@ impl MpscChannel {
                         pat_type_sender   = quote!{ #sender: #type_sender, };
                         pat_type_receiver = quote!{ #receiver: #type_receiver, };
                         declaration       = quote!{ let ( #sender, #receiver ) =  async_channel::unbounded(); }; 
+                        sender_sync_call  = quote!{ let _ = #send_try.expect(#error);};
+                        sender_sync_send  = quote!{ #send_try };
                    },
                }
             },
@@ -156,12 +220,16 @@ impl MpscChannel {
                match  lib { 
             
                    Lib::Std      => {
-                        type_sender       = quote!{ std::sync::mpsc::SyncSender<#script_type> };    
-                        type_receiver     = quote!{ std::sync::mpsc::Receiver<#script_type> };
+                        let (sender_ty, receiver_ty, channel_decl) = channel_impl.mailbox(script_type, Some(val));
+                        type_sender       = sender_ty;    
+                        type_receiver     = receiver_ty;
                         pat_type_sender   = quote!{ #sender: #type_sender, };
                         pat_type_receiver = quote!{ #receiver: #type_receiver, };
-                        declaration       = quote!{ let ( #sender, #receiver ) = std::sync::mpsc::sync_channel(#val); };
-                        sender_call       = quote!{ let _ = self.#sender.send(#msg).expect(#error);};
+                        declaration       = quote!{ let ( #sender, #receiver ) = #channel_decl; };
+                        sender_call       = quote!{ let _ = #send_sync.expect(#error);};
+                        sender_call_err   = quote!{ #send_sync #map_err };
+                        sender_send       = quote!{ #send_sync };
+                        sender_try_call   = try_call(channel_impl.try_send_error());
                    },
                    Lib::Tokio    => {
                         type_sender       = quote!{ tokio::sync::mpsc::Sender<#script_type> };    
@@ -169,6 +237,7 @@ impl MpscChannel {
                         pat_type_sender   = quote!{ #sender: #type_sender, };
                         pat_type_receiver = quote!{ mut #receiver: #type_receiver, };
                         declaration       = quote!{ let ( #sender, #receiver ) = tokio::sync::mpsc::channel(#val); };               
+                        sender_try_call   = try_call(quote!{ tokio::sync::mpsc::error::TrySendError });
                    },
             
                    Lib::AsyncStd  => {
@@ -177,6 +246,7 @@ impl MpscChannel {
                         pat_type_sender   = quote!{ #sender: #type_sender, };
                         pat_type_receiver = quote!{ #receiver: #type_receiver, };
                         declaration       = quote!{ let ( #sender, #receiver ) = async_std::channel::bounded(#val); };
+                        sender_try_call   = try_call(quote!{ async_std::channel::TrySendError });
                    },
             
                    Lib::Smol      => {
@@ -185,11 +255,16 @@ impl MpscChannel {
                         pat_type_sender   = quote!{ #sender: #type_sender, };
                         pat_type_receiver = quote!{ #receiver: #type_receiver, };
                         declaration       = quote!{ let ( #sender, #receiver ) = async_channel::bounded(#val); };
+                        sender_try_call   = try_call(quote!{ async_channel::TrySendError });
                    },
                }
             },
         };
 
+        // the live holds a sender for each instance of the pool
+        let pat_type_sender = 
+        if pool.active(){ quote!{ #sender: std::vec::Vec<#type_sender>, } } else { pat_type_sender };
+
         Self {
             type_sender,
             type_receiver,
@@ -197,6 +272,11 @@ impl MpscChannel {
             pat_type_receiver,
             declaration,  
             sender_call, 
+            sender_call_err,
+            sender_send,
+            sender_try_call,
+            sender_sync_call,
+            sender_sync_send,
         }
     }
 
@@ -218,3 +298,74 @@ impl Default for Channel {
     }
 }
 
+
+
+//-----------------------  ACTOR CHANNEL IMPL 
+
+// the crate of the `std` actor's mailbox
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub enum ChannelImpl {
+    #[default]
+    Std,
+    Crossbeam,
+    Flume,
+}
+
+impl ChannelImpl {
+
+    pub fn from( meta: &Meta ) -> Self {
+        match get_lit_str(meta,"channel_impl").as_str() {
+            "std"       => ChannelImpl::Std,
+            "crossbeam" => ChannelImpl::Crossbeam,
+            "flume"     => ChannelImpl::Flume,
+            val => {
+                let msg = format!("Unknown option  -  {:?} for 'channel_impl' ", val);
+                abort!( meta, msg; help=error::AVAIL_CHANNEL_IMPL );
+            }
+        }
+    }
+
+    pub fn active(&self) -> bool {
+        !ChannelImpl::Std.eq(self)
+    }
+
+    // ( sender type, receiver type, declaration )
+    pub fn mailbox(&self, script_type: &Type, buffer: Option<&syn::LitInt> ) -> (TokenStream,TokenStream,TokenStream) {
+        match (self, buffer) {
+            (ChannelImpl::Std, None) => (
+                quote!{ std::sync::mpsc::Sender<#script_type> },
+                quote!{ std::sync::mpsc::Receiver<#script_type> },
+                quote!{ std::sync::mpsc::channel() }),
+            (ChannelImpl::Std, Some(val)) => (
+                quote!{ std::sync::mpsc::SyncSender<#script_type> },
+                quote!{ std::sync::mpsc::Receiver<#script_type> },
+                quote!{ std::sync::mpsc::sync_channel(#val) }),
+            (ChannelImpl::Crossbeam, buffer) => (
+                quote!{ crossbeam_channel::Sender<#script_type> },
+                quote!{ crossbeam_channel::Receiver<#script_type> },
+                buffer.map(|val| quote!{ crossbeam_channel::bounded(#val) })
+                      .unwrap_or(quote!{ crossbeam_channel::unbounded() })),
+            (ChannelImpl::Flume, buffer) => (
+                quote!{ flume::Sender<#script_type> },
+                quote!{ flume::Receiver<#script_type> },
+                buffer.map(|val| quote!{ flume::bounded(#val) })
+                      .unwrap_or(quote!{ flume::unbounded() })),
+        }
+    }
+
+    pub fn try_send_error(&self) -> TokenStream {
+        match self {
+            ChannelImpl::Std       => quote!{ std::sync::mpsc::TrySendError },
+            ChannelImpl::Crossbeam => quote!{ crossbeam_channel::TrySendError },
+            ChannelImpl::Flume     => quote!{ flume::TrySendError },
+        }
+    }
+
+    pub fn recv_timeout_error(&self) -> TokenStream {
+        match self {
+            ChannelImpl::Std       => quote!{ std::sync::mpsc::RecvTimeoutError },
+            ChannelImpl::Crossbeam => quote!{ crossbeam_channel::RecvTimeoutError },
+            ChannelImpl::Flume     => quote!{ flume::RecvTimeoutError },
+        }
+    }
+}
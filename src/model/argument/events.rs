@@ -0,0 +1,164 @@
+use crate::error;
+use crate::model::{to_usize,Cont,Lib,Vars};
+
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::quote;
+use syn::{Ident,LitInt,Meta,Token,Type,Visibility,parse::ParseStream};
+
+
+//-----------------------  ACTOR EVENTS
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Events {
+    pub ty:  Option<Type>,
+    pub capacity:  usize,
+}
+
+impl Default for Events {
+    fn default() -> Self {
+        Self{ ty: None, capacity: 64 }
+    }
+}
+
+impl Events {
+
+    pub fn active(&self) -> bool {
+        self.ty.is_some()
+    }
+
+    // `events(Type)` or `events(Type, capacity = n)`
+    pub fn parse(&mut self, meta: &Meta){
+
+        let Meta::List(meta_list) = meta else {
+            abort!(meta,error::EXPECT_LIST;help=error::AVAIL_EVENTS);
+        };
+
+        let parser = |input: ParseStream| -> syn::Result<(Type,Option<LitInt>)> {
+            let ty: Type = input.parse()?;
+            let mut capacity = None;
+            if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+                let name: Ident = input.parse()?;
+                if name != "capacity" {
+                    return Err(syn::Error::new(name.span(),"Unknown option for argument 'events'."));
+                }
+                input.parse::<Token![=]>()?;
+                capacity = Some(input.parse::<LitInt>()?);
+                input.parse::<Option<Token![,]>>()?;
+            }
+            Ok((ty,capacity))
+        };
+
+        match meta_list.parse_args_with(parser) {
+            Ok((ty,capacity)) => {
+                if let Some(capacity) = capacity {
+                    self.capacity = to_usize(&capacity);
+                    if self.capacity == 0 {
+                        abort!(capacity,"Expected a capacity greater than zero.";help=error::AVAIL_EVENTS);
+                    }
+                }
+                self.ty = Some(ty);
+            },
+            Err(e) => { abort!(e.span(),e.to_string();help=error::AVAIL_EVENTS); },
+        }
+    }
+
+    fn types(&self, lib: &Lib) -> (TokenStream,TokenStream) {
+        let ty = self.ty.as_ref().unwrap();
+        match lib {
+            Lib::Std => (
+                quote!{ std::sync::Arc<std::sync::Mutex<std::vec::Vec<std::sync::mpsc::Sender<#ty>>>> },
+                quote!{ std::sync::mpsc::Receiver<#ty> }),
+            Lib::Tokio => (
+                quote!{ tokio::sync::broadcast::Sender<#ty> },
+                quote!{ tokio::sync::broadcast::Receiver<#ty> }),
+            Lib::AsyncStd |
+            Lib::Smol => (
+                // the inactive receiver keeps the channel open
+                quote!{ (async_broadcast::Sender<#ty>, async_broadcast::InactiveReceiver<#ty>) },
+                quote!{ async_broadcast::Receiver<#ty> }),
+        }
+    }
+
+    pub fn live_field(&self, Vars{ inter_events,.. }: &Vars, lib: &Lib ) -> TokenStream {
+        let (type_sender,_) = self.types(lib);
+        quote!{ pub #inter_events: #type_sender, }
+    }
+
+    // declared in `new`
+    pub fn decl(&self, Vars{ inter_events,.. }: &Vars, lib: &Lib ) -> TokenStream {
+        let capacity = self.capacity;
+        match lib {
+            Lib::Std => quote!{
+                let #inter_events = std::sync::Arc::new(std::sync::Mutex::new(std::vec::Vec::new()));
+            },
+            Lib::Tokio => quote!{
+                let ( #inter_events, _ ) = tokio::sync::broadcast::channel(#capacity);
+            },
+            Lib::AsyncStd |
+            Lib::Smol => quote!{
+                let #inter_events = {
+                    let ( mut inter_sender, inter_receiver ) = async_broadcast::broadcast(#capacity);
+                    inter_sender.set_overflow(true);
+                    ( inter_sender, inter_receiver.deactivate() )
+                };
+            },
+        }
+    }
+
+    // `subscribe` and the getter of `inter_emit`
+    pub fn impl_events(&self,
+        Cont{ live_mets,.. }: &mut Cont,
+        Vars{ inter_events, subscribe, inter_get_emit,.. }: &Vars,
+        lib: &Lib,
+        new_vis: &Option<Visibility>,
+    ){
+        let ty = self.ty.as_ref().unwrap();
+        let (_,type_receiver) = self.types(lib);
+
+        let (subscribe_body, emit_body) = match lib {
+            Lib::Std => (
+                quote!{
+                    let ( inter_sender, inter_receiver ) = std::sync::mpsc::channel();
+                    self.#inter_events.lock().unwrap().push(inter_sender);
+                    inter_receiver
+                },
+                // the subscribers which are gone are removed
+                quote!{
+                    let #inter_events = std::sync::Arc::clone(&self.#inter_events);
+                    move |inter_event: #ty| {
+                        #inter_events.lock().unwrap().retain(|inter_sender| inter_sender.send(inter_event.clone()).is_ok());
+                    }
+                }),
+            Lib::Tokio => (
+                quote!{ self.#inter_events.subscribe() },
+                quote!{
+                    let #inter_events = self.#inter_events.clone();
+                    move |inter_event: #ty| { let _ = #inter_events.send(inter_event); }
+                }),
+            Lib::AsyncStd |
+            Lib::Smol => (
+                quote!{ self.#inter_events.1.activate_cloned() },
+                quote!{
+                    let #inter_events = self.#inter_events.0.clone();
+                    move |inter_event: #ty| { let _ = #inter_events.try_broadcast(inter_event); }
+                }),
+        };
+
+        live_mets.push((subscribe.clone(),
+            quote!{
+                #new_vis fn #subscribe (&self) -> #type_receiver {
+                    #subscribe_body
+                }
+            }
+        ));
+
+        live_mets.push((inter_get_emit.clone(),
+            quote!{
+                #new_vis fn #inter_get_emit (&self) -> impl Fn(#ty) + Send + Sync + 'static {
+                    #emit_body
+                }
+            }
+        ));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::model::{ActorAttributeArguments,Cont,Vars};
+
+use proc_macro2::TokenStream;
+use quote::{quote,format_ident};
+use syn::{Ident,Visibility};
+
+
+//-----------------------  ACTOR ERROR
+
+pub struct ErrorVariant {
+    pub ident:  Ident,
+    pub doc:   String,
+    pub msg:   String,
+}
+
+impl ErrorVariant {
+
+    pub fn new( ident: &str, doc: &str, msg: &str ) -> Self {
+        Self{ ident: format_ident!("{ident}"), doc: doc.to_string(), msg: msg.to_string() }
+    }
+}
+
+pub fn error_variants( aaa: &ActorAttributeArguments ) -> Vec<ErrorVariant> {
+
+    let mut variants = vec![
+        ErrorVariant::new("SendClosed",
+            "The actor is no longer receiving messages.",
+            "sending on a closed channel"),
+        ErrorVariant::new("ReplyDropped",
+            "The actor dropped the message before sending a reply.",
+            "reply channel is closed"),
+    ];
+    if aaa.try_send {
+        variants.push(ErrorVariant::new("Full",
+            "The actor's channel is full.",
+            "channel is full"));
+    }
+    if aaa.timeout.active() {
+        variants.push(ErrorVariant::new("Timeout",
+            "The actor did not reply in time.",
+            "reply timed out"));
+    }
+    variants
+}
+
+// returns the definition of error enum
+// and pushes the traits to the `live` traits
+pub fn impl_error(
+    Cont{ live_trts,.. }: &mut Cont,
+    Vars{ live_name, error_name,.. }: &Vars,
+    aaa: &ActorAttributeArguments,
+    new_vis: &Option<Visibility>,
+) -> TokenStream {
+
+    let variants = error_variants(aaa);
+
+    let def_variants = variants.iter().map(|ErrorVariant{ident,doc,..}|{
+        quote!{ #[doc = #doc] #ident }
+    });
+
+    let display_arms = variants.iter().map(|ErrorVariant{ident,msg,..}|{
+        let msg = format!("'{live_name}' {msg}.");
+        quote!{ #error_name :: #ident => write!(f, #msg), }
+    });
+
+    live_trts.push((format_ident!("Display"),
+    quote!{
+        impl std::fmt::Display for #error_name {
+            fn fmt( &self, f: &mut std::fmt::Formatter<'_> ) -> std::fmt::Result {
+                match self {
+                    #(#display_arms)*
+                }
+            }
+        }
+    }));
+
+    live_trts.push((format_ident!("Error"),
+    quote!{
+        impl std::error::Error for #error_name {}
+    }));
+
+    quote!{
+        #[derive(Debug,Clone,Copy,PartialEq,Eq)]
+        #new_vis enum #error_name {
+            #(#def_variants),*
+        }
+    }
+}
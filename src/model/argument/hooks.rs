@@ -0,0 +1,133 @@
+use crate::error;
+use crate::model::{get_ident_value,get_list,Cont,Vars};
+
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::quote;
+use syn::{Ident,ItemImpl,Meta,Visibility};
+
+
+//-----------------------  ACTOR HOOKS
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Hooks {
+    pub start:  Option<Ident>,
+    pub stop:   Option<Ident>,
+    pub before: Option<Ident>,
+    pub after:  Option<Ident>,
+}
+
+impl Hooks {
+
+    pub fn active(&self) -> bool {
+        self.start.is_some() || self.stop.is_some() || self.observe()
+    }
+
+    // the messages are observed
+    pub fn observe(&self) -> bool {
+        self.before.is_some() || self.after.is_some()
+    }
+
+    pub fn parse(&mut self, meta: &Meta){
+
+        if let Some(meta_list) = get_list( meta,Some(error::AVAIL_HOOKS) ) {
+
+            crate::model::check_path_set(&meta_list);
+
+            for m in meta_list.iter() {
+
+                if m.path().is_ident("start"){
+                    self.start = Some(get_ident_value(m,"start"));
+                }
+                else if m.path().is_ident("stop"){
+                    self.stop = Some(get_ident_value(m,"stop"));
+                }
+                else if m.path().is_ident("before"){
+                    self.before = Some(get_ident_value(m,"before"));
+                }
+                else if m.path().is_ident("after"){
+                    self.after = Some(get_ident_value(m,"after"));
+                }
+                else {
+                    let msg = "Unknown option for argument 'hooks'.";
+                    abort!(m,msg;help=error::AVAIL_HOOKS);
+                }
+            }
+            if !self.active() {
+                abort!(meta,error::HOOKS_REQUIRED;help=error::AVAIL_HOOKS);
+            }
+        } else { abort!(meta,error::EXPECT_LIST;help=error::AVAIL_HOOKS); }
+    }
+
+    fn call(&self, Vars{ actor,.. }: &Vars, item_impl: &ItemImpl, hook: &Option<Ident>, args: TokenStream ) -> Option<TokenStream> {
+        hook.as_ref().map(|method|
+            crate::model::impl_method_call(item_impl, actor, method, args, error::AVAIL_HOOKS)
+        )
+    }
+
+    // called at the beginning of `play`, on the actor's thread (task)
+    pub fn play_start(&self, vars: &Vars, item_impl: &ItemImpl ) -> Option<TokenStream> {
+        self.call(vars, item_impl, &self.start, quote!{})
+    }
+
+    // called after the `play` loop
+    pub fn play_stop(&self, vars: &Vars, item_impl: &ItemImpl ) -> Option<TokenStream> {
+        self.call(vars, item_impl, &self.stop, quote!{})
+    }
+
+    // the `direct` call of a message between
+    // the `before` and `after` hooks
+    pub fn play_direct(&self, vars: &Vars, item_impl: &ItemImpl, direct_call: TokenStream ) -> TokenStream {
+
+        if !self.observe(){ return direct_call; }
+
+        let Vars{ msg, inter_variant,.. } = vars;
+        let arg = quote!{ #inter_variant };
+        let before = self.call(vars, item_impl, &self.before, arg.clone());
+        let after  = self.call(vars, item_impl, &self.after, arg);
+        let before = before.map(|before| quote!{ if let std::option::Option::Some(#inter_variant) = #inter_variant { #before } });
+        let after  = after.map(|after| quote!{ if let std::option::Option::Some(#inter_variant) = #inter_variant { #after } });
+
+        quote!{
+            {
+                let #inter_variant = #msg.#inter_variant();
+                #before
+                #direct_call;
+                #after
+            }
+        }
+    }
+}
+
+
+// the variant name of a message as observed by the hooks and metrics,
+// the variants of the model itself are not observed
+pub fn impl_variant(
+    Cont{ script_mets, script_fields,.. }: &mut Cont,
+    Vars{ script_name, inter_variant, inter_shutdown, inter_wake, inter_trace,.. }: &Vars,
+    new_vis: &Option<Visibility>,
+){
+    let inter_variants = [inter_shutdown,inter_wake,inter_trace].map(crate::model::name::script_field);
+
+    let mut arms = Vec::new();
+    for (variant,_) in script_fields.iter() {
+        let value = 
+        if inter_variants.contains(variant) { 
+            quote!{ std::option::Option::None }
+        } else {
+            let str_variant = variant.to_string();
+            quote!{ std::option::Option::Some(#str_variant) }
+        };
+        arms.push(quote!{ #script_name :: #variant {..} => #value, });
+    }
+
+    script_mets.push((inter_variant.clone(),
+        quote!{
+            #new_vis fn #inter_variant (&self) -> std::option::Option<&'static str> {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    ));
+}
@@ -0,0 +1,64 @@
+use crate::error;
+use crate::model::get_list;
+
+use proc_macro_error::abort;
+use syn::{Ident,Meta};
+
+
+//-----------------------  ACTOR METHOD LIST
+
+// an option applied to all the methods that return
+// a type, or to the listed ones (`cast`, `deferred`),
+// `help` is the `AVAIL_*` text of the option
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct MethodList {
+    pub all:      bool,
+    pub methods:  Vec<Ident>,
+}
+
+impl MethodList {
+
+    pub fn active(&self) -> bool {
+        self.all || !self.methods.is_empty()
+    }
+
+    pub fn parse(&mut self, meta: &Meta, help: &str){
+
+        match meta {
+            Meta::Path(_) => { self.all = true; },
+            _ => {
+                if let Some(meta_list) = get_list( meta,Some(help) ) {
+
+                    crate::model::check_path_set(&meta_list);
+
+                    for m in meta_list.iter() {
+                        match m {
+                            Meta::Path(path) => {
+                                if let Some(ident) = path.get_ident(){
+                                    self.methods.push(ident.clone());
+                                } else { abort!(m,error::EXPECT_IDENT;help=help); }
+                            },
+                            _ => { abort!(m,error::EXPECT_IDENT;help=help); },
+                        }
+                    }
+                } else { abort!(meta,error::EXPECT_LIST;help=help); }
+            },
+        }
+    }
+
+    // the method gets a counterpart (`cast_`, `_deferred`)
+    pub fn contains(&self, ident: &Ident) -> bool {
+        self.all || self.methods.contains(ident)
+    }
+
+    // every method named in the list must be
+    // a method that returns a type
+    pub fn check_methods(&self, returns: &[Ident], help: &str){
+        for ident in self.methods.iter(){
+            if !returns.contains(ident){
+                let msg = format!("No method named `{ident}` returning a type in Actor's methods.");
+                abort!(ident,msg;help=help);
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::model::{Cont,Vars};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Visibility;
+
+
+//-----------------------  ACTOR METRICS
+
+// the snapshot type returned by `metrics`
+pub fn metrics_def( Vars{ metrics_name,.. }: &Vars, new_vis: &Option<Visibility> ) -> TokenStream {
+    quote!{
+        #[derive(Debug,Clone,Default)]
+        #new_vis struct #metrics_name {
+            pub queue_depth: usize,
+            pub processed: std::collections::BTreeMap<&'static str, u64>,
+            pub elapsed: std::collections::BTreeMap<&'static str, std::time::Duration>,
+        }
+    }
+}
+
+fn metrics_type( Vars{ metrics_name,.. }: &Vars ) -> TokenStream {
+    quote!{ std::sync::Arc<std::sync::Mutex<#metrics_name>> }
+}
+
+pub fn metrics_live_field( vars: &Vars ) -> TokenStream {
+    let Vars{ inter_metrics,.. } = vars;
+    let metrics_type = metrics_type(vars);
+    quote!{ pub #inter_metrics: #metrics_type, }
+}
+
+// declared in `new`
+pub fn metrics_decl( Vars{ inter_metrics, metrics_name,.. }: &Vars ) -> TokenStream {
+    quote!{ let #inter_metrics = std::sync::Arc::new(std::sync::Mutex::new(#metrics_name::default())); }
+}
+
+// ( play argument, play parameter )
+pub fn metrics_play_arg( vars: &Vars ) -> (TokenStream,TokenStream) {
+    let Vars{ inter_metrics,.. } = vars;
+    let metrics_type = metrics_type(vars);
+    ( quote!{ , inter_metrics_play }, quote!{ , #inter_metrics: #metrics_type } )
+}
+
+// a clone for every spawned `play`
+pub fn metrics_spawn( Vars{ inter_metrics,.. }: &Vars, spawn: TokenStream ) -> TokenStream {
+    quote!{
+        let inter_metrics_play = std::sync::Arc::clone(&#inter_metrics);
+        #spawn
+    }
+}
+
+// every message sent through the live sender is queued,
+// counted before the send (`play` may receive it first)
+// and taken back if the send fails
+pub fn metrics_send( Vars{ inter_metrics,.. }: &Vars, send: TokenStream ) -> TokenStream {
+    quote!{
+        ({
+            self.#inter_metrics.lock().unwrap().queue_depth += 1;
+            let inter_sent = #send;
+            if inter_sent.is_err() {
+                let mut inter_metrics_guard = self.#inter_metrics.lock().unwrap();
+                inter_metrics_guard.queue_depth = inter_metrics_guard.queue_depth.saturating_sub(1);
+            }
+            inter_sent
+        })
+    }
+}
+
+// the message leaves the queue before it is intercepted,
+// the control messages are queued as well
+pub fn metrics_play_dequeue( Vars{ inter_metrics,.. }: &Vars ) -> TokenStream {
+    quote!{
+        {
+            let mut inter_metrics_guard = #inter_metrics.lock().unwrap();
+            inter_metrics_guard.queue_depth = inter_metrics_guard.queue_depth.saturating_sub(1);
+        }
+    }
+}
+
+// the processing time of a message 
+// is added up under its variant name
+pub fn metrics_play_direct( Vars{ msg, inter_variant, inter_metrics,.. }: &Vars, direct_call: TokenStream ) -> TokenStream {
+    quote!{
+        {
+            let #inter_variant = #msg.#inter_variant();
+            let inter_start = std::time::Instant::now();
+            #direct_call;
+            if let std::option::Option::Some(#inter_variant) = #inter_variant {
+                let inter_elapsed = inter_start.elapsed();
+                let mut inter_metrics_guard = #inter_metrics.lock().unwrap();
+                *inter_metrics_guard.processed.entry(#inter_variant).or_default() += 1;
+                *inter_metrics_guard.elapsed.entry(#inter_variant).or_default() += inter_elapsed;
+            }
+        }
+    }
+}
+
+pub fn impl_metrics(
+    Cont{ live_mets,.. }: &mut Cont,
+    Vars{ inter_metrics, metrics, metrics_name,.. }: &Vars,
+    new_vis: &Option<Visibility>,
+){
+    live_mets.push((metrics.clone(),
+        quote!{
+            #new_vis fn #metrics (&self) -> #metrics_name {
+                self.#inter_metrics.lock().unwrap().clone()
+            }
+        }
+    ));
+}
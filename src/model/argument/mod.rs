@@ -1,12 +1,52 @@
 pub mod channel;
 pub mod debut;
 pub mod edit;
+pub mod events;
+pub mod fallible;
+pub mod hooks;
 pub mod interact;
+pub mod method_list;
+pub mod metrics;
+pub mod on_end;
+pub mod pool;
+pub mod priority;
+pub mod registry;
+pub mod remote;
+pub mod serde;
+pub mod shutdown;
+pub mod spawner;
+pub mod supervise;
+pub mod thread;
+pub mod tick;
+pub mod timeout;
+pub mod trace;
+pub mod weak;
+pub mod with;
 
 pub use channel::*;
 pub use debut::*;
 pub use edit::*;
+pub use events::*;
+pub use fallible::*;
+pub use hooks::*;
 pub use interact::*;
+pub use method_list::*;
+pub use metrics::*;
+pub use on_end::*;
+pub use pool::*;
+pub use priority::*;
+pub use registry::*;
+pub use remote::*;
+pub use self::serde::*;
+pub use shutdown::*;
+pub use spawner::*;
+pub use supervise::*;
+pub use thread::*;
+pub use tick::*;
+pub use timeout::*;
+pub use trace::*;
+pub use weak::*;
+pub use with::*;
 
 
 
@@ -15,7 +55,7 @@ use crate::error;
 use proc_macro2::TokenStream;
 use proc_macro_error::abort;
 use quote::quote;
-use syn::{Ident,Meta};
+use syn::{Ident,Meta,Visibility};
 
 use std::path::PathBuf;
 
@@ -77,24 +117,136 @@ impl Lib {
 
     }
     
-    pub fn method_new_spawn(&self, play_args: &TokenStream, script_name: &Ident) -> TokenStream {
+    // the async actors are spawned with the `spawner`
+    // (same signature as the library `spawn`) if any
+    pub fn method_new_spawn(&self, play_args: &TokenStream, script_name: &Ident, (thread_spawn,thread_res): &(TokenStream,TokenStream), spawner: Option<&TokenStream>) -> TokenStream {
 
         match &self {
             Lib::Std      => {
-                quote!{ std::thread::spawn(move|| { #script_name :: play(#play_args) } );}
+                quote!{ #thread_spawn(move|| { #script_name :: play(#play_args) } ) #thread_res;}
             },
             Lib::Smol     => {
-                quote!{ smol::spawn( #script_name :: play(#play_args) ).detach();} 
+                let spawn = spawner.cloned().unwrap_or(quote!{ smol::spawn });
+                quote!{ #spawn( #script_name :: play(#play_args) ).detach();} 
             },
             Lib::Tokio    => {
-                quote!{ tokio::spawn( #script_name :: play(#play_args) );}
+                let spawn = spawner.cloned().unwrap_or(quote!{ tokio::spawn });
+                quote!{ #spawn( #script_name :: play(#play_args) );}
             },
             Lib::AsyncStd => {
-                quote!{ async_std::task::spawn( #script_name :: play(#play_args) );}
+                let spawn = spawner.cloned().unwrap_or(quote!{ async_std::task::spawn });
+                quote!{ #spawn( #script_name :: play(#play_args) );}
             },
         }
     }
 
+    // keeps the handle of spawned thread (task) 
+    pub fn method_new_spawn_handle(&self, play_args: &TokenStream, script_name: &Ident, handle: &Ident, task_name: &Ident, (thread_spawn,thread_res): &(TokenStream,TokenStream), spawner: Option<&TokenStream>) -> TokenStream {
+
+        let spawn = match &self {
+            Lib::Std      => {
+                quote!{ #thread_spawn(move|| { #script_name :: play(#play_args) } ) #thread_res}
+            },
+            Lib::Smol     => {
+                let spawn = spawner.cloned().unwrap_or(quote!{ smol::spawn });
+                quote!{ #spawn( #script_name :: play(#play_args) )} 
+            },
+            Lib::Tokio    => {
+                let spawn = spawner.cloned().unwrap_or(quote!{ tokio::spawn });
+                quote!{ #spawn( #script_name :: play(#play_args) )}
+            },
+            Lib::AsyncStd => {
+                let spawn = spawner.cloned().unwrap_or(quote!{ async_std::task::spawn });
+                quote!{ #spawn( #script_name :: play(#play_args) )}
+            },
+        };
+        let task = 
+        if Lib::Smol.eq(self) { quote!{ #task_name(std::option::Option::Some( #spawn )) } } 
+        else { quote!{ std::option::Option::Some( #spawn ) } };
+        quote!{ let #handle = std::sync::Arc::new(std::sync::Mutex::new( #task )); }
+    }
+
+    // the type kept in the `handle` field
+    pub fn handle_field_type(&self, task_name: &Ident) -> TokenStream {
+
+        match &self {
+            Lib::Smol => quote!{ #task_name },
+            _ => {
+                let handle_type = self.handle_type();
+                quote!{ std::option::Option<#handle_type> }
+            },
+        }
+    }
+
+    // a `smol::Task` is cancelled when dropped, the task is 
+    // detached if the handle was not taken by the last `live`
+    pub fn handle_def(&self, task_name: &Ident, new_vis: &Option<Visibility>) -> Option<TokenStream> {
+
+        match &self {
+            Lib::Smol => Some(quote!{
+                #new_vis struct #task_name(std::option::Option<smol::Task<()>>);
+
+                impl std::ops::Deref for #task_name {
+                    type Target = std::option::Option<smol::Task<()>>;
+                    fn deref(&self) -> &Self::Target { &self.0 }
+                }
+
+                impl std::ops::DerefMut for #task_name {
+                    fn deref_mut(&mut self) -> &mut Self::Target { &mut self.0 }
+                }
+
+                impl Drop for #task_name {
+                    fn drop(&mut self){
+                        if let std::option::Option::Some(task) = self.0.take() { task.detach(); }
+                    }
+                }
+            }),
+            _ => None,
+        }
+    }
+
+    // the next message of `play` loop as an `Option`
+    pub fn recv_next(&self, receiver: &Ident) -> TokenStream {
+
+        match &self {
+            Lib::Std      => quote!{ #receiver.recv().ok() },
+            Lib::Tokio    => quote!{ #receiver.recv().await },
+            Lib::AsyncStd |
+            Lib::Smol     => quote!{ #receiver.recv().await.ok() },
+        }
+    }
+
+    // the messages left in an `async_channel` outlive its receivers, 
+    // they are dropped when `play` returns or unwinds, so a caller 
+    // waiting for a reply gets an error instead of waiting forever
+    pub fn play_drain(&self, receivers: &[&Ident]) -> Option<TokenStream> {
+
+        match &self {
+            Lib::Std      |
+            Lib::Tokio    => None,
+            Lib::AsyncStd |
+            Lib::Smol     => Some(quote!{
+                struct InterDrain<F: FnMut()>(F);
+                impl<F: FnMut()> Drop for InterDrain<F> {
+                    fn drop(&mut self){ (self.0)() }
+                }
+                let _inter_drain = InterDrain(|| {
+                    #( #receivers.close(); while #receivers.try_recv().is_ok() {} )*
+                });
+            }),
+        }
+    }
+
+    pub fn handle_type(&self) -> TokenStream {
+
+        match &self {
+            Lib::Std      => quote!{ std::thread::JoinHandle<()> },
+            Lib::Smol     => quote!{ smol::Task<()> },
+            Lib::Tokio    => quote!{ tokio::task::JoinHandle<()> },
+            Lib::AsyncStd => quote!{ async_std::task::JoinHandle<()> },
+        }
+    }
+
 
 }
 
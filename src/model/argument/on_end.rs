@@ -0,0 +1,118 @@
+use crate::error;
+use crate::model::{get_lit_str,Debut,ImplVars,Vars};
+
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::quote;
+use syn::{Meta,Path,Visibility};
+
+
+//-----------------------  ACTOR ON_END
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub enum OnEnd {
+    #[default]
+    Stderr,
+    Silent,
+    Log,
+    Tracing,
+    Callback(Path),
+}
+
+impl OnEnd {
+
+    pub fn from( meta: &Meta ) -> Self {
+        if let Meta::NameValue(name_value) = meta {
+            if let syn::Expr::Path(expr_path) = &name_value.value {
+                return OnEnd::Callback(expr_path.path.clone());
+            }
+        }
+        match get_lit_str(meta,"on_end").as_str() {
+            "stderr"  => OnEnd::Stderr,
+            "silent"  => OnEnd::Silent,
+            "log"     => OnEnd::Log,
+            "tracing" => OnEnd::Tracing,
+            val => {
+                let msg = format!("Unknown option  -  {:?} for 'on_end' ", val);
+                abort!( meta, msg; help=error::AVAIL_ON_END );
+            }
+        }
+    }
+
+    pub fn is_callback(&self) -> bool {
+        matches!(self, OnEnd::Callback(_))
+    }
+
+    // the exit reason passed to the callback
+    pub fn end_def(&self, Vars{ end_name,.. }: &Vars, new_vis: &Option<Visibility> ) -> Option<TokenStream> {
+        if !self.is_callback() { return None; }
+        Some(quote!{
+            #[derive(Debug,Clone,Copy,PartialEq,Eq)]
+            #new_vis enum #end_name {
+                Closed,
+                Panicked,
+            }
+        })
+    }
+
+    // returns ( declaration at the beginning of `play`, end of `play` ),
+    // the report of a panic is left to the guard dropped while unwinding
+    pub fn play_end(&self, Vars{ debut, end_name, impl_vars,.. }: &Vars, debut_opt: &Debut ) -> (Option<TokenStream>,Option<TokenStream>) {
+
+        let ImplVars{ actor_name,.. } = impl_vars;
+
+        let report = match self {
+            OnEnd::Stderr => { return (None, Some(error::end_of_life(actor_name, debut_opt))); },
+            OnEnd::Silent => { return (None, None); },
+            OnEnd::Log => {
+                let (closed, panicked) = messages(actor_name, debut_opt);
+                let debut_arg = if debut_opt.active(){ Some(quote!{ , self.0.unwrap() }) } else { None };
+                quote!{
+                    if inter_panicked { log::error!(#panicked #debut_arg); }
+                    else { log::info!(#closed #debut_arg); }
+                }
+            },
+            OnEnd::Tracing => {
+                let str_actor = actor_name.to_string();
+                quote!{
+                    if inter_panicked { tracing::error!( actor = #str_actor, debut = ?self.0, "panicked" ); }
+                    else { tracing::info!( actor = #str_actor, debut = ?self.0, "the end" ); }
+                }
+            },
+            OnEnd::Callback(path) => {
+                let str_actor = actor_name.to_string();
+                quote!{
+                    let inter_reason = if inter_panicked { #end_name::Panicked } else { #end_name::Closed };
+                    #path ( #str_actor, self.0, inter_reason );
+                }
+            },
+        };
+
+        let debut_init =
+        if debut_opt.active(){ quote!{ std::option::Option::Some(#debut) } }
+        else { quote!{ std::option::Option::None } };
+
+        (Some(quote!{
+            struct InterEnd(std::option::Option<std::time::SystemTime>);
+            impl std::ops::Drop for InterEnd {
+                fn drop(&mut self) {
+                    let inter_panicked = std::thread::panicking();
+                    #report
+                }
+            }
+            let inter_end_guard = InterEnd(#debut_init);
+        }),
+        Some(quote!{ drop(inter_end_guard); }))
+    }
+}
+
+// ( normal end, panic ) messages
+fn messages( actor_name: &syn::Ident, debut: &Debut ) -> (String,String) {
+    if debut.active(){
+        let closed = if debut.is_legend(){ format!("{actor_name} [ {{:?}} ] to be continued ...") }
+                     else { format!("{actor_name} [ {{:?}} ] the end ...") };
+        (closed, format!("{actor_name} [ {{:?}} ] panicked ..."))
+    } else {
+        (format!("{actor_name} the end ..."), format!("{actor_name} panicked ..."))
+    }
+}
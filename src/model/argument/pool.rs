@@ -0,0 +1,295 @@
+use crate::error;
+use crate::model::{get_ident_value,get_lit,get_lit_str,get_list,to_usize,ActorMethod,ActorMethodNew,Cont,ImplVars,Vars};
+
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::quote;
+use syn::{FnArg,Ident,Meta};
+
+
+//-----------------------  ACTOR POOL
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub enum Dispatch {
+    #[default]
+    RoundRobin,
+    LeastQueued,
+    KeyHash,
+}
+
+impl Dispatch {
+
+    pub fn from( meta: &Meta ) -> Self {
+        match get_lit_str(meta,"dispatch").as_str() {
+            "round_robin"  => Dispatch::RoundRobin,
+            "least_queued" => Dispatch::LeastQueued,
+            "key_hash"     => Dispatch::KeyHash,
+            val => {
+                let msg = format!("Unknown option  -  {:?} for 'dispatch' ", val);
+                abort!( meta, msg; help=error::AVAIL_POOL );
+            }
+        }
+    }
+}
+
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Pool {
+    pub size:  Option<usize>,
+    pub dispatch:   Dispatch,
+    pub key:   Option<Ident>,
+}
+
+impl Pool {
+
+    pub fn active(&self) -> bool {
+        self.size.is_some()
+    }
+
+    pub fn parse(&mut self, meta: &Meta){
+
+        if let Some(meta_list) = get_list( meta,Some(error::AVAIL_POOL) ) {
+
+            crate::model::check_path_set(&meta_list);
+
+            for m in meta_list.iter() {
+
+                if m.path().is_ident("size"){
+                    match get_lit(m) {
+                        syn::Lit::Int(val) => {
+                            let size = to_usize(&val);
+                            if size == 0 { abort!(val,error::POOL_SIZE;help=error::AVAIL_POOL); }
+                            self.size = Some(size);
+                        },
+                        v => abort!(v, error::error_name_type( m.path(), "Int (usize)"); help=error::AVAIL_POOL ),
+                    }
+                }
+                else if m.path().is_ident("dispatch"){
+                    self.dispatch = Dispatch::from(m);
+                }
+                else if m.path().is_ident("key"){
+                    self.key = Some(get_ident_value(m,"key"));
+                }
+                else {
+                    let msg = "Unknown option for argument 'pool'.";
+                    abort!(m,msg;help=error::AVAIL_POOL);
+                }
+            }
+            if self.size.is_none() {
+                abort!(meta,error::POOL_SIZE;help=error::AVAIL_POOL);
+            }
+            if Dispatch::KeyHash.eq(&self.dispatch) != self.key.is_some() {
+                abort!(meta,error::POOL_KEY;help=error::AVAIL_POOL);
+            }
+        } else { abort!(meta,error::EXPECT_LIST;help=error::AVAIL_POOL); }
+    }
+
+    // the message is sent to the chosen instance, a failed
+    // send takes the message back from the instance's queue length
+    pub fn live_send(&self, Vars{ sender, msg, inter_pool, inter_pool_index,.. }: &Vars, call: TokenStream ) -> TokenStream {
+        match self.dispatch {
+            Dispatch::LeastQueued => quote!{
+                ({
+                    let inter_index = self.#inter_pool_index();
+                    let inter_sent = self.#sender[inter_index].#call;
+                    if inter_sent.is_err() {
+                        self.#inter_pool[inter_index].fetch_sub(1, std::sync::atomic::Ordering::Relaxed);
+                    }
+                    inter_sent
+                })
+            },
+            Dispatch::KeyHash => quote!{ self.#sender[self.#inter_pool_index(&#msg)].#call },
+            Dispatch::RoundRobin => quote!{ self.#sender[self.#inter_pool_index()].#call },
+        }
+    }
+
+    // the live field, a counter shared between clones
+    // or the queue lengths of the instances
+    pub fn live_field(&self, Vars{ inter_pool,.. }: &Vars ) -> TokenStream {
+        match self.dispatch {
+            Dispatch::LeastQueued => quote!{ #inter_pool: std::vec::Vec<std::sync::Arc<std::sync::atomic::AtomicUsize>>, },
+                                _ => quote!{ #inter_pool: std::sync::Arc<std::sync::atomic::AtomicUsize>, },
+        }
+    }
+
+    // an instance tracks its queue length
+    pub fn play_arg(&self, Vars{ inter_queued,.. }: &Vars ) -> Option<(TokenStream,TokenStream)> {
+        match self.dispatch {
+            Dispatch::LeastQueued => Some((
+                quote!{ , #inter_queued },
+                quote!{ , #inter_queued: std::sync::Arc<std::sync::atomic::AtomicUsize> })),
+                                _ => None,
+        }
+    }
+
+    pub fn play_direct(&self, Vars{ inter_queued,.. }: &Vars, direct_call: TokenStream ) -> TokenStream {
+        match self.dispatch {
+            Dispatch::LeastQueued => quote!{
+                {
+                    #direct_call;
+                    #inter_queued.fetch_sub(1, std::sync::atomic::Ordering::Relaxed);
+                }
+            },
+                                _ => direct_call,
+        }
+    }
+
+    // the body of `new` up to the return statement,
+    // each instance is created by its own call of `new`
+    pub fn new_spawn(&self,
+        Vars{ actor, sender, inter_pool, inter_queued,.. }: &Vars,
+        met_new: &ActorMethodNew,
+        ImplVars{ actor_name,.. }: &ImplVars,
+        declaration: &TokenStream,
+        vars_debut:  &TokenStream,
+        spawn:       &TokenStream,
+    ) -> TokenStream {
+
+        let size = self.size.unwrap();
+        let func_new_name = &met_new.new_sig.ident;
+        let unwrapped     = met_new.unwrap_sign();
+
+        let mut idents = Vec::new();
+        for arg in met_new.get_arguments() {
+            if let FnArg::Typed(pat_type) = &arg {
+                match &*pat_type.pat {
+                    syn::Pat::Ident(pat_ident) => idents.push(pat_ident.ident.clone()),
+                    _ => {
+                        abort!(pat_type, error::POOL_PATTERN_ARG; help=error::AVAIL_POOL);
+                    },
+                }
+            }
+        }
+
+        let (pool_decl, queued_decl, pool_init) = match self.dispatch {
+            Dispatch::LeastQueued => (
+                quote!{ let mut #inter_pool = std::vec::Vec::with_capacity(#size); },
+                quote!{
+                    let #inter_queued = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+                    #inter_pool.push(std::sync::Arc::clone(&#inter_queued));
+                },
+                quote!{}),
+                                _ => (
+                quote!{},
+                quote!{},
+                quote!{ let #inter_pool = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0)); }),
+        };
+
+        quote!{
+            #vars_debut
+            #pool_decl
+            let mut inter_senders = std::vec::Vec::with_capacity(#size);
+            for _ in 0..#size {
+                let #actor = #actor_name :: #func_new_name ( #(#idents.clone()),* ) #unwrapped;
+                #declaration
+                #queued_decl
+                #spawn
+                inter_senders.push(#sender);
+            }
+            let #sender = inter_senders;
+            #pool_init
+        }
+    }
+
+    // the live method choosing the instance, methods taking
+    // the `key` argument are hashed, others are round robin
+    pub fn impl_pool(&self,
+        Cont{ live_mets, script_mets, script_fields,.. }: &mut Cont,
+        Vars{ sender, inter_pool, inter_pool_index, inter_key, script_name, script_type, impl_vars,.. }: &Vars,
+        cast: &crate::model::MethodList,
+    ){
+        let round_robin = quote!{
+            #inter_pool.fetch_add(1, std::sync::atomic::Ordering::Relaxed) % self.#sender.len()
+        };
+
+        let body = match self.dispatch {
+            Dispatch::RoundRobin => quote!{
+                fn #inter_pool_index (&self) -> usize {
+                    self.#round_robin
+                }
+            },
+            Dispatch::LeastQueued => quote!{
+                fn #inter_pool_index (&self) -> usize {
+                    let index = self.#inter_pool.iter()
+                        .enumerate()
+                        .min_by_key(|(_,queued)| queued.load(std::sync::atomic::Ordering::Relaxed))
+                        .map(|(index,_)| index)
+                        .unwrap_or(0);
+                    self.#inter_pool[index].fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    index
+                }
+            },
+            Dispatch::KeyHash => {
+                script_mets.push((inter_key.clone(), self.key_method(inter_key, script_name, impl_vars, script_fields, cast)));
+                quote!{
+                    fn #inter_pool_index (&self, msg: &#script_type) -> usize {
+                        match msg.#inter_key() {
+                            std::option::Option::Some(hash) => (hash % self.#sender.len() as u64) as usize,
+                            std::option::Option::None => self.#round_robin,
+                        }
+                    }
+                }
+            },
+        };
+        live_mets.push((inter_pool_index.clone(), body));
+    }
+
+    // the hash of the `key` argument of a message
+    fn key_method(&self, inter_key: &Ident, script_name: &Ident, ImplVars{ actor_methods,.. }: &ImplVars,
+                  script_fields: &[(Ident,TokenStream)], cast: &crate::model::MethodList ) -> TokenStream {
+
+        let key = self.key.as_ref().unwrap();
+        let mut arms = Vec::new();
+
+        for method in actor_methods {
+            let (arguments, returns) = match method {
+                ActorMethod::Io{ stat: false, arguments,.. } => (arguments, true),
+                ActorMethod::I { arguments,.. }              => (arguments, false),
+                _ => continue,
+            };
+            let mut has_key = false;
+            let pats = arguments.iter().filter_map(|arg|
+                match arg {
+                    FnArg::Typed(pat_type) => match &*pat_type.pat {
+                        syn::Pat::Ident(pat_ident) if pat_ident.ident.eq(key) => { has_key = true; Some(quote!{ #key }) },
+                        _ => Some(quote!{ _ }),
+                    },
+                    _ => None,
+                }).collect::<Vec<_>>();
+            if !has_key { continue; }
+
+            let (sig, variant) = method.get_sig_and_field_name();
+            let mut variants = vec![variant];
+            if returns && cast.contains(&sig.ident) {
+                variants.push(crate::model::name::script_field(&crate::model::name::cast_method(&sig.ident)));
+            }
+            for variant in variants {
+                arms.push(quote!{
+                    #script_name :: #variant { input: ( #(#pats),* ), .. } => {
+                        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+                        std::hash::Hash::hash(#key, &mut hasher);
+                        std::option::Option::Some(std::hash::Hasher::finish(&hasher))
+                    },
+                });
+            }
+        }
+
+        if arms.is_empty() {
+            let msg = format!("No method with an argument named `{key}` in Actor's methods.");
+            abort!(key,msg;help=error::AVAIL_POOL);
+        }
+
+        // every variant of the script takes the key
+        let variant_count = script_fields.len();
+        let other = if arms.len() < variant_count { Some(quote!{ _ => std::option::Option::None, }) } else { None };
+
+        quote!{
+            fn #inter_key (&self) -> std::option::Option<u64> {
+                match self {
+                    #(#arms)*
+                    #other
+                }
+            }
+        }
+    }
+}
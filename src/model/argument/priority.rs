@@ -0,0 +1,163 @@
+use crate::error;
+use crate::model::{get_list,ActorAttributeArguments,Channel,Cont,Lib,MpscChannel,Vars};
+
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::quote;
+use syn::{Ident,Meta};
+
+
+//-----------------------  ACTOR PRIORITY
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Priority {
+    pub high: Vec<Ident>,
+}
+
+impl Priority {
+
+    pub fn active(&self) -> bool {
+        !self.high.is_empty()
+    }
+
+    pub fn parse(&mut self, meta: &Meta){
+
+        if let Some(meta_list) = get_list( meta,Some(error::AVAIL_PRIORITY) ) {
+
+            crate::model::check_path_set(&meta_list);
+
+            for m in meta_list.iter() {
+
+                if m.path().is_ident("high"){
+                    if let Some(list) = get_list( m,Some(error::AVAIL_PRIORITY) ){
+                        for h in list.iter() {
+                            match h {
+                                Meta::Path(path) if path.get_ident().is_some() => {
+                                    self.high.push(path.get_ident().unwrap().clone());
+                                },
+                                _ => { abort!(h,error::EXPECT_IDENT;help=error::AVAIL_PRIORITY); },
+                            }
+                        }
+                    } else { abort!(m,error::EXPECT_LIST;help=error::AVAIL_PRIORITY); }
+                }
+                else {
+                    let msg = "Unknown option for argument 'priority'.";
+                    abort!(m,msg;help=error::AVAIL_PRIORITY);
+                }
+            }
+        } else { abort!(meta,error::EXPECT_LIST;help=error::AVAIL_PRIORITY); }
+    }
+
+    // the method is sent through the high priority lane
+    pub fn contains(&self, ident: &Ident) -> bool {
+        self.high.contains(ident)
+    }
+
+    // every method named in the list must be 
+    // a method sent to the actor
+    pub fn check_methods(&self, sent: &[Ident]){
+        for ident in self.high.iter(){
+            if !sent.contains(ident){
+                let msg = format!("No method named `{ident}` in Actor's methods.");
+                abort!(ident,msg;help=error::AVAIL_PRIORITY);
+            }
+        }
+    }
+
+    // the high priority lane is an unbounded channel 
+    // returns ( live field, play argument, declaration )
+    pub fn lane(&self, Vars{ sender_high, receiver_high, script_type,.. }: &Vars, lib: &Lib ) -> (TokenStream,TokenStream,TokenStream) {
+
+        let (type_sender,type_receiver,channel,mut_recv) = match lib {
+            Lib::Std => (
+                quote!{ std::sync::mpsc::Sender<#script_type> },
+                quote!{ std::sync::mpsc::Receiver<#script_type> },
+                quote!{ std::sync::mpsc::channel() }, None ),
+            Lib::Tokio => (
+                quote!{ tokio::sync::mpsc::UnboundedSender<#script_type> },
+                quote!{ tokio::sync::mpsc::UnboundedReceiver<#script_type> },
+                quote!{ tokio::sync::mpsc::unbounded_channel() }, Some(quote!{mut}) ),
+            Lib::AsyncStd => (
+                quote!{ async_std::channel::Sender<#script_type> },
+                quote!{ async_std::channel::Receiver<#script_type> },
+                quote!{ async_std::channel::unbounded() }, None ),
+            Lib::Smol => (
+                quote!{ async_channel::Sender<#script_type> },
+                quote!{ async_channel::Receiver<#script_type> },
+                quote!{ async_channel::unbounded() }, None ),
+        };
+        ( quote!{ pub #sender_high: #type_sender, },
+          quote!{ , #mut_recv #receiver_high: #type_receiver },
+          quote!{ let ( #sender_high, #receiver_high ) = #channel; })
+    }
+
+    // the next message of `play` loop, the high priority lane first
+    pub fn play_recv(&self, Vars{ receiver_high,.. }: &Vars, recv_low: &TokenStream ) -> TokenStream {
+        quote!{
+            match #receiver_high.try_recv() {
+                std::result::Result::Ok(inter_high) => std::option::Option::Some(inter_high),
+                std::result::Result::Err(_) => #recv_low,
+            }
+        }
+    }
+
+    // calls of the methods sent through the high priority lane,
+    // followed by a wake message on the main channel 
+    pub fn high_lane(&self, 
+        Vars{ sender, sender_high, script_name, inter_wake, live_name, error_name, msg,.. }: &Vars,
+        ActorAttributeArguments{ lib, channel,.. }: &ActorAttributeArguments,
+        mpsc: &MpscChannel ) -> MpscChannel {
+
+        let wake_variant = crate::model::name::script_field(inter_wake);
+        let error = format!("'{live_name}::method.send'. Channel is closed!");
+
+        let high_send = match lib {
+            Lib::Std | Lib::Tokio => quote!{ self.#sender_high.send(#msg) },
+            _ => quote!{ self.#sender_high.try_send(#msg) },
+        };
+        // a full channel is busy anyway, the lane is checked before every message
+        let wake = match (lib,channel) {
+            (Lib::Std,Channel::Unbounded) | 
+            (Lib::Tokio,Channel::Unbounded) => quote!{ let _ = self.#sender.send(#script_name :: #wake_variant {}); },
+            _ => quote!{ let _ = self.#sender.try_send(#script_name :: #wake_variant {}); },
+        };
+        let send_wake = quote!{ { let inter_sent = #high_send; #wake inter_sent } };
+        let call      = quote!{ let _ = #send_wake.expect(#error); };
+        let call_err  = quote!{ #send_wake.map_err(|_error| #error_name :: SendClosed)?; };
+
+        let mut high = mpsc.clone();
+        high.sender_call      = call.clone();
+        high.sender_call_err  = call_err.clone();
+        high.sender_send      = send_wake.clone();
+        high.sender_try_call  = call_err;
+        high.sender_sync_call = call;
+        high.sender_sync_send = send_wake;
+        high
+    }
+
+    // the wake message 
+    pub fn impl_priority(&self,
+        Cont{
+            direct_arms,
+            debug_arms,
+            script_fields,..
+        }: &mut Cont,
+        Vars{ script_name, inter_wake,.. }: &Vars,
+    ){
+        let variant = crate::model::name::script_field(inter_wake);
+
+        // Script Field Struct
+        script_fields.push((variant.clone(),quote!{ #variant {} }));
+
+        // Direct Arm
+        direct_arms.push(quote!{
+            #script_name :: #variant {} => {},
+        });
+
+        // Debug Arm
+        let str_variant = format!("{script_name}::{variant}");
+        debug_arms.push(quote!{
+            #script_name :: #variant {..} => write!(f, #str_variant),
+        });
+    }
+}
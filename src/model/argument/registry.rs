@@ -0,0 +1,116 @@
+use crate::error;
+use crate::model::{Cont,Vars,ImplVars};
+
+use proc_macro2::TokenStream;
+use proc_macro::Span;
+use proc_macro_error::abort;
+use quote::quote;
+use syn::Visibility;
+
+
+//-----------------------  ACTOR REGISTRY
+
+// every `new` call gets its own id,
+// the entries of an actor are removed by id
+pub fn registry_decl( Vars{ inter_registry,.. }: &Vars ) -> TokenStream {
+    quote!{
+        let #inter_registry = {
+            static INTER_ID: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+            INTER_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+        };
+    }
+}
+
+// declared first in `play`, dropped when the actor
+// stops (or panics) it deregisters the actor,
+// returns ( declaration, drop before the `join` signal )
+pub fn registry_guard( Vars{ script_name, inter_registry, inter_get_registry,.. }: &Vars ) -> (TokenStream,TokenStream) {
+    (quote!{
+        struct InterRegistry(usize);
+        impl std::ops::Drop for InterRegistry {
+            fn drop(&mut self) {
+                #script_name :: #inter_get_registry ()
+                    .lock()
+                    .unwrap_or_else(|error| error.into_inner())
+                    .retain(|_, (id,_)| *id != self.0);
+            }
+        }
+        let inter_registry_guard = InterRegistry(#inter_registry);
+    },
+    quote!{ drop(inter_registry_guard); })
+}
+
+pub fn impl_registry(
+    Cont{ live_mets, script_mets,.. }: &mut Cont,
+    Vars{
+        name,
+        live_name,
+        script_name,
+        intername,
+        inter_registry,
+        inter_get_registry,
+        register,
+        lookup,
+        registered,
+        deregister,
+        impl_vars,..
+    }: &Vars,
+    new_vis: &Option<Visibility>,
+){
+    let ImplVars{ model_generics,.. } = impl_vars;
+
+    if crate::model::is_generic(model_generics){
+        abort!(Span::call_site(),error::REGISTRY_LIMIT_GENERIC);
+    }
+
+    script_mets.push((inter_get_registry.clone(),
+        quote!{
+            #new_vis fn #inter_get_registry () -> &'static std::sync::Mutex<std::collections::BTreeMap<String,(usize,#live_name)>> {
+                static COLLECTION: std::sync::Mutex<std::collections::BTreeMap<String,(usize,#live_name)>> =
+                std::sync::Mutex::new(std::collections::BTreeMap::new());
+                &COLLECTION
+            }
+        }
+    ));
+
+    let collection = quote!{ #script_name :: #inter_get_registry ().lock().unwrap_or_else(|error| error.into_inner()) };
+
+    // the collection keeps the registered actor alive
+    live_mets.push((register.clone(),
+        quote!{
+            #new_vis fn #register <#intername:std::string::ToString>(&self, #name: #intername) -> std::option::Option<#live_name> {
+                #collection
+                    .insert(#name.to_string(), (self.#inter_registry, self.clone()))
+                    .map(|(_,live)| live)
+            }
+        }
+    ));
+
+    live_mets.push((lookup.clone(),
+        quote!{
+            #new_vis fn #lookup <#intername:std::string::ToString>(#name: #intername) -> std::option::Option<#live_name> {
+                #collection
+                    .get(&#name.to_string())
+                    .map(|(_,live)| live.clone())
+            }
+        }
+    ));
+
+    live_mets.push((registered.clone(),
+        quote!{
+            #new_vis fn #registered () -> std::vec::Vec<String> {
+                #collection.keys().cloned().collect()
+            }
+        }
+    ));
+
+    live_mets.push((deregister.clone(),
+        quote!{
+            #new_vis fn #deregister <#intername:std::string::ToString>(#name: #intername) -> std::option::Option<#live_name> {
+                #collection
+                    .remove(&#name.to_string())
+                    .map(|(_,live)| live)
+            }
+        }
+    ));
+}
@@ -0,0 +1,257 @@
+use crate::model::{arguments_pat_type,ActorAttributeArguments,ActorMethod,ImplVars,Lib,OneshotChannel,Vars};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{FnArg,Visibility};
+
+
+//-----------------------  ACTOR REMOTE
+
+// ( stream bounds, mutex type, lock call, await )
+fn transport( lib: &Lib ) -> (TokenStream,TokenStream,TokenStream,Option<TokenStream>) {
+    match lib {
+        Lib::Std => (
+            quote!{ std::io::Read + std::io::Write },
+            quote!{ std::sync::Mutex },
+            quote!{ .lock().unwrap_or_else(|error| error.into_inner()) },
+            None ),
+        Lib::Tokio => (
+            quote!{ tokio::io::AsyncRead + tokio::io::AsyncWrite + std::marker::Unpin },
+            quote!{ tokio::sync::Mutex },
+            quote!{ .lock().await },
+            Some(quote!{ .await }) ),
+        Lib::AsyncStd => (
+            quote!{ async_std::io::Read + async_std::io::Write + std::marker::Unpin },
+            quote!{ async_std::sync::Mutex },
+            quote!{ .lock().await },
+            Some(quote!{ .await }) ),
+        Lib::Smol => (
+            quote!{ smol::io::AsyncRead + smol::io::AsyncWrite + std::marker::Unpin },
+            quote!{ smol::lock::Mutex },
+            quote!{ .lock().await },
+            Some(quote!{ .await }) ),
+    }
+}
+
+// ( read_exact, write_all, flush ) paths
+fn io_calls( lib: &Lib ) -> (TokenStream,TokenStream,TokenStream) {
+    match lib {
+        Lib::Std      => ( quote!{ std::io::Read::read_exact },
+                           quote!{ std::io::Write::write_all },
+                           quote!{ std::io::Write::flush } ),
+        Lib::Tokio    => ( quote!{ tokio::io::AsyncReadExt::read_exact },
+                           quote!{ tokio::io::AsyncWriteExt::write_all },
+                           quote!{ tokio::io::AsyncWriteExt::flush } ),
+        Lib::AsyncStd => ( quote!{ async_std::io::ReadExt::read_exact },
+                           quote!{ async_std::io::WriteExt::write_all },
+                           quote!{ async_std::io::WriteExt::flush } ),
+        Lib::Smol     => ( quote!{ smol::io::AsyncReadExt::read_exact },
+                           quote!{ smol::io::AsyncWriteExt::write_all },
+                           quote!{ smol::io::AsyncWriteExt::flush } ),
+    }
+}
+
+// the limit of the length of a frame, 
+// a peer can not make the other side allocate more
+static MAX_FRAME: usize = 16 * 1024 * 1024;
+
+// the `XReply` enum, the `XRemote` client
+// and the `XServer` serving a live instance
+pub fn remote_def( vars: &Vars, aaa: &ActorAttributeArguments, new_vis: &Option<Visibility> ) -> TokenStream {
+
+    let Vars{
+        live_name,
+        script_name,
+        inter_send,
+        inter_replay_keep,
+        remote_name,
+        server_name,
+        reply_name,
+        inter_read_frame,
+        inter_write_frame,
+        impl_vars,
+        inter_error,..
+    } = vars;
+    let ImplVars{ actor_methods,.. } = impl_vars;
+    let lib = &aaa.lib;
+
+    let (bounds, mutex, lock, await_call) = transport(lib);
+    let (read_exact, write_all, flush) = io_calls(lib);
+    let async_decl = await_call.as_ref().map(|_| quote!{ async });
+    let max_frame  = MAX_FRAME;
+
+    let invalid = quote!{ std::io::Error::new(std::io::ErrorKind::InvalidData, error) };
+
+    let mut reply_variants = Vec::new();
+    let mut remote_mets    = Vec::new();
+    let mut server_arms    = Vec::new();
+
+    for method in actor_methods {
+
+        let (sig, variant) = method.get_sig_and_field_name();
+        let ident = &sig.ident;
+
+        let (arguments, output, fallible_met) = match method {
+            ActorMethod::Io{ stat: true,..} | ActorMethod::O{ stat: true,..} => continue,
+            ActorMethod::Io{ arguments, output,..} =>
+                (arguments.clone(), Some(output), aaa.fallible || aaa.timeout.get(ident).is_some()),
+            ActorMethod::O{ output,..} =>
+                (vec![], Some(output), aaa.fallible || aaa.timeout.get(ident).is_some()),
+            ActorMethod::I{ arguments,..} => (arguments.clone(), None, aaa.fallible),
+            ActorMethod::None{..} => (vec![], None, aaa.fallible),
+        };
+        let (args_ident,_) = arguments_pat_type(&arguments);
+        let input = if arguments.is_empty(){ None } else { Some(quote!{ input: #args_ident, }) };
+        let args = arguments.iter().filter(|arg| matches!(arg, FnArg::Typed(_)));
+        let vis = match method {
+            ActorMethod::Io{ vis,..} | ActorMethod::I{ vis,..} |
+            ActorMethod::O{ vis,..} | ActorMethod::None{ vis,..} => vis,
+        };
+
+        match output {
+            Some(output) => {
+                let decl = OneshotChannel::get_decl(lib, Some(output));
+                reply_variants.push(quote!{ #variant ( #output ), });
+                remote_mets.push(quote!{
+                    #[allow(unreachable_patterns)]
+                    #vis #async_decl fn #ident (&self, #(#args),* ) -> std::io::Result<#output> {
+                        let (#inter_send, _) = #decl;
+                        let msg = #script_name :: #variant { #input #inter_send };
+                        let mut stream = self.stream #lock;
+                        Self:: #inter_write_frame (&mut *stream, &msg) #await_call ?;
+                        match Self:: #inter_read_frame (&mut *stream) #await_call ? {
+                            std::option::Option::Some(#reply_name :: #variant (output)) => std::result::Result::Ok(output),
+                            std::option::Option::Some(#reply_name :: #inter_error (error)) => std::result::Result::Err(std::io::Error::other(error)),
+                            std::option::Option::Some(_) => std::result::Result::Err({ let error = "unexpected reply"; #invalid }),
+                            std::option::Option::None => std::result::Result::Err(std::io::ErrorKind::UnexpectedEof.into()),
+                        }
+                    }
+                });
+                // an error of the live instance is sent back to the client
+                let reply = 
+                if fallible_met {
+                    quote!{
+                        match self.live.#ident #args_ident #await_call {
+                            std::result::Result::Ok(output) => #reply_name :: #variant (output),
+                            std::result::Result::Err(error) => #reply_name :: #inter_error (format!("{error:?}")),
+                        }
+                    }
+                } else {
+                    quote!{ #reply_name :: #variant (self.live.#ident #args_ident #await_call) }
+                };
+                server_arms.push(quote!{
+                    #script_name :: #variant { #input .. } => std::option::Option::Some(#reply),
+                });
+            },
+            None => {
+                remote_mets.push(quote!{
+                    #vis #async_decl fn #ident (&self, #(#args),* ) -> std::io::Result<()> {
+                        let msg = #script_name :: #variant { #input };
+                        let mut stream = self.stream #lock;
+                        Self:: #inter_write_frame (&mut *stream, &msg) #await_call
+                    }
+                });
+                // the client does not wait for a reply,
+                // an error of the live instance is dropped
+                let ignore = if fallible_met { Some(quote!{ let _ = }) } else { None };
+                server_arms.push(quote!{
+                    #script_name :: #variant { #input .. } => {
+                        #ignore self.live.#ident #args_ident #await_call;
+                        std::option::Option::None
+                    },
+                });
+            },
+        }
+    }
+
+    quote!{
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        #new_vis enum #reply_name {
+            #(#reply_variants)*
+            // an error of the live instance (`fallible`, `timeout`)
+            #inter_error (std::string::String),
+        }
+
+        #new_vis struct #remote_name <S> {
+            stream: std::sync::Arc<#mutex<S>>,
+        }
+
+        impl<S> std::clone::Clone for #remote_name <S> {
+            fn clone(&self) -> Self {
+                Self{ stream: std::sync::Arc::clone(&self.stream) }
+            }
+        }
+
+        impl<S: #bounds> #remote_name <S> {
+
+            #new_vis fn new(stream: S) -> Self {
+                Self{ stream: std::sync::Arc::new(#mutex::new(stream)) }
+            }
+
+            // a frame is a `u32` big endian length followed by the JSON of the value,
+            // the length is limited to 16 MiB
+            #new_vis #async_decl fn #inter_write_frame <T: serde::Serialize>(stream: &mut S, value: &T) -> std::io::Result<()> {
+                let bytes = serde_json::to_vec(value).map_err(|error| #invalid)?;
+                if bytes.len() > #max_frame {
+                    let error = "frame too large";
+                    return std::result::Result::Err(#invalid);
+                }
+                let len = u32::try_from(bytes.len()).map_err(|error| #invalid)?;
+                #write_all(stream, &len.to_be_bytes()) #await_call ?;
+                #write_all(stream, &bytes) #await_call ?;
+                #flush(stream) #await_call
+            }
+
+            // `None` if the stream is closed between two frames
+            #new_vis #async_decl fn #inter_read_frame <T: serde::de::DeserializeOwned>(stream: &mut S) -> std::io::Result<std::option::Option<T>> {
+                let mut len = [0u8; 4];
+                match #read_exact(stream, &mut len) #await_call {
+                    std::result::Result::Ok(_) => (),
+                    std::result::Result::Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => {
+                        return std::result::Result::Ok(std::option::Option::None);
+                    },
+                    std::result::Result::Err(error) => return std::result::Result::Err(error),
+                }
+                let len = u32::from_be_bytes(len) as usize;
+                if len > #max_frame {
+                    let error = "frame too large";
+                    return std::result::Result::Err(#invalid);
+                }
+                let mut bytes = vec![0u8; len];
+                #read_exact(stream, &mut bytes) #await_call ?;
+                serde_json::from_slice(&bytes).map(std::option::Option::Some).map_err(|error| #invalid)
+            }
+
+            #(#remote_mets)*
+        }
+
+        #new_vis struct #server_name <S> {
+            live:   #live_name,
+            stream: S,
+        }
+
+        #[allow(unreachable_patterns)]
+        impl<S: #bounds> #server_name <S> {
+
+            #new_vis fn new(live: #live_name, stream: S) -> Self {
+                Self{ live, stream }
+            }
+
+            // handles the messages of a client until the stream is closed
+            #new_vis #async_decl fn serve(mut self) -> std::io::Result<()> {
+                while let std::option::Option::Some(msg) = #remote_name :: #inter_read_frame (&mut self.stream) #await_call ? {
+                    let reply: std::option::Option<#reply_name> = match msg {
+                        #(#server_arms)*
+                        _ => std::option::Option::None,
+                    };
+                    #script_name :: #inter_replay_keep (std::option::Option::None);
+                    if let std::option::Option::Some(reply) = reply {
+                        #remote_name :: #inter_write_frame (&mut self.stream, &reply) #await_call ?;
+                    }
+                }
+                std::result::Result::Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use crate::error;
+use crate::model::{get_list,get_lit_str,Cont,ImplVars,Lib,OneshotChannel,Vars};
+
+use proc_macro2::TokenStream;
+use proc_macro::Span;
+use proc_macro_error::abort;
+use quote::quote;
+use syn::{Meta,Visibility};
+
+
+//-----------------------  ACTOR SERDE
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Serde {
+    pub active:  bool,
+    pub journal: Option<String>,
+}
+
+impl Serde {
+
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    pub fn parse(&mut self, meta: &Meta){
+
+        self.active = true;
+
+        if let Some(meta_list) = get_list( meta,Some(error::AVAIL_SERDE) ) {
+
+            crate::model::check_path_set(&meta_list);
+
+            for m in meta_list.iter() {
+
+                if m.path().is_ident("journal"){
+                    self.journal = Some(get_lit_str(m,"journal"));
+                }
+                else {
+                    let msg = "Unknown option for argument 'serde'.";
+                    abort!(m,msg;help=error::AVAIL_SERDE);
+                }
+            }
+        }
+    }
+
+    pub fn script_attr(&self) -> Option<TokenStream> {
+        if !self.active() { return None; }
+        Some(quote!{ #[derive(serde::Serialize, serde::Deserialize)] })
+    }
+
+    // opened at the beginning of `play`, a journal that 
+    // can not be opened is reported once, the actor runs without it
+    pub fn play_decl(&self, Vars{ inter_journal, impl_vars,.. }: &Vars ) -> Option<TokenStream> {
+        let journal = self.journal.as_ref()?;
+        let error = format!("{} failed to open the journal file {journal:?}: {{}}", impl_vars.actor_name);
+        Some(quote!{
+            let mut #inter_journal = 
+            match std::fs::OpenOptions::new().create(true).append(true).open(#journal) {
+                std::result::Result::Ok(file) => std::option::Option::Some(file),
+                std::result::Result::Err(error) => { eprintln!(#error, error); std::option::Option::None },
+            };
+        })
+    }
+
+    // every handled message is appended to the journal,
+    // a line of JSON, before it is handled
+    pub fn play_direct(&self, Vars{ msg, inter_journal,.. }: &Vars, direct_call: TokenStream ) -> TokenStream {
+        if self.journal.is_none() { return direct_call; }
+        quote!{
+            {
+                if let std::option::Option::Some(inter_file) = #inter_journal.as_mut() {
+                    if let std::result::Result::Ok(mut inter_line) = serde_json::to_vec(&#msg) {
+                        inter_line.push(b'\n');
+                        let _ = std::io::Write::write_all(inter_file, &inter_line);
+                    }
+                }
+                #direct_call;
+            }
+        }
+    }
+
+    pub fn impl_serde(&self,
+        Cont{ script_mets, script_fields,.. }: &mut Cont,
+        Vars{
+            actor,
+            msg,
+            direct,
+            script_name,
+            inter_send,
+            replay,
+            inter_replay_sender,
+            inter_replay_keep,
+            impl_vars,..
+        }: &Vars,
+        lib: &Lib,
+        new_vis: &Option<Visibility>,
+    ){
+        let ImplVars{ actor_type, async_decl, model_generics,.. } = impl_vars;
+
+        if crate::model::is_generic(model_generics){
+            abort!(Span::call_site(),error::SERDE_LIMIT_GENERIC);
+        }
+
+        // the oneshot senders are not serialized,
+        // a new one is created on deserialization
+        let str_default = format!("{script_name}::{inter_replay_sender}");
+        for (_,field) in script_fields.iter_mut() {
+            let mut variant: syn::Variant = syn::parse2(field.clone()).unwrap();
+            for f in variant.fields.iter_mut() {
+                if f.ident.as_ref().map(|i| i.eq(inter_send)).unwrap_or(false) {
+                    let is_option =
+                    if let syn::Type::Path(type_path) = &f.ty {
+                        type_path.path.segments.last().map(|s| s.ident == "Option").unwrap_or(false)
+                    } else { false };
+                    if is_option { f.attrs.push(syn::parse_quote!{ #[serde(skip)] }); }
+                    else { f.attrs.push(syn::parse_quote!{ #[serde(skip, default = #str_default)] }); }
+                }
+            }
+            *field = quote!{ #variant };
+        }
+
+        let ty = syn::parse_quote!{ T };
+        let decl = OneshotChannel::get_decl(lib, None);
+        let send_type = OneshotChannel::get_send_type(lib, &ty);
+
+        script_mets.push((inter_replay_keep.clone(),
+            quote!{
+                // the receivers of the replayed messages
+                fn #inter_replay_keep (recv: std::option::Option<std::boxed::Box<dyn std::any::Any>>) {
+                    std::thread_local!{
+                        static INTER_RECEIVERS: std::cell::RefCell<std::vec::Vec<std::boxed::Box<dyn std::any::Any>>> =
+                        std::cell::RefCell::new(std::vec::Vec::new());
+                    }
+                    INTER_RECEIVERS.with(|receivers| match recv {
+                        std::option::Option::Some(recv) => receivers.borrow_mut().push(recv),
+                        std::option::Option::None => receivers.borrow_mut().clear(),
+                    });
+                }
+            }
+        ));
+
+        script_mets.push((inter_replay_sender.clone(),
+            quote!{
+                fn #inter_replay_sender <T: 'static>() -> #send_type {
+                    let (send, recv) = #decl;
+                    Self:: #inter_replay_keep (std::option::Option::Some(std::boxed::Box::new(recv)));
+                    send
+                }
+            }
+        ));
+
+        let await_call = async_decl.as_ref().map(|_| quote!{.await});
+
+        script_mets.push((replay.clone(),
+            quote!{
+                #new_vis #async_decl fn #replay <P: std::convert::AsRef<std::path::Path>>(path: P, #actor: &mut #actor_type) -> std::io::Result<()> {
+                    let journal = std::fs::read_to_string(path)?;
+                    for line in journal.lines().filter(|line| !line.trim().is_empty()) {
+                        let #msg: Self = serde_json::from_str(line).map_err(std::io::Error::from)?;
+                        #msg.#direct(#actor) #await_call;
+                        Self:: #inter_replay_keep (std::option::Option::None);
+                    }
+                    std::result::Result::Ok(())
+                }
+            }
+        ));
+    }
+}
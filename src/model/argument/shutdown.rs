@@ -0,0 +1,144 @@
+use crate::error;
+use crate::model::{get_ident_value,get_list,Cont,Lib,MpscChannel,OneshotChannel,Vars};
+
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::quote;
+use syn::{Ident,ItemImpl,Meta,Visibility,Type};
+
+
+//-----------------------  ACTOR SHUTDOWN
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Shutdown {
+    pub active:           bool,
+    pub on_stop: Option<Ident>,
+}
+
+impl Shutdown {
+
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    pub fn parse(&mut self, meta: &Meta){
+
+        self.active = true;
+
+        if let Some(meta_list) = get_list( meta,Some(error::AVAIL_SHUTDOWN) ) {
+
+            crate::model::check_path_set(&meta_list);
+
+            for m in meta_list.iter() {
+
+                if m.path().is_ident("on_stop"){
+                    self.on_stop = Some(get_ident_value(m,"on_stop"));
+                }
+                else {
+                    let msg = "Unknown option for argument 'shutdown'.";
+                    abort!(m,msg;help=error::AVAIL_SHUTDOWN);
+                }
+            }
+        }
+    }
+
+    // intercepts the control variant at the beginning of `play` loop,
+    // the reply channel of `inter_join` is kept until the end of `play`
+    pub fn play_intercept(&self, Vars{ script_name, msg, inter_send, inter_joins, inter_shutdown,.. }: &Vars ) -> TokenStream {
+        let variant = crate::model::name::script_field(inter_shutdown);
+        quote!{
+            if let #script_name :: #variant { #inter_send } = #msg {
+                #inter_joins = #inter_send;
+                break;
+            }
+        }
+    }
+
+    pub fn play_decl(&self, Vars{ inter_joins,.. }: &Vars ) -> TokenStream {
+        quote!{ let mut #inter_joins = std::option::Option::None; }
+    }
+
+    // called after the `play` loop
+    pub fn play_end(&self, Vars{ actor, inter_joins,.. }: &Vars, item_impl: &ItemImpl ) -> (Option<TokenStream>,TokenStream) {
+
+        let on_stop = self.on_stop.as_ref().map(|on_stop|
+            crate::model::impl_method_call(item_impl, actor, on_stop, quote!{}, error::AVAIL_SHUTDOWN)
+        );
+        let join = quote!{
+            if let std::option::Option::Some(#inter_joins) = #inter_joins {
+                let _ = #inter_joins.send(());
+            }
+        };
+        (on_stop, join)
+    }
+
+    pub fn impl_shutdown(&self,
+        Cont{
+            live_mets,
+            direct_arms,
+            debug_arms,
+            script_fields,..
+        }: &mut Cont,
+        Vars{
+            script_name,
+            inter_shutdown,
+            inter_join,
+            inter_send,
+            inter_recv,
+            msg,
+            impl_vars,..
+        }: &Vars,
+        lib:                         &Lib,
+        new_vis:       &Option<Visibility>,
+        MpscChannel{ sender_send,.. }: &MpscChannel,
+    ){
+        let variant   = crate::model::name::script_field(inter_shutdown);
+        let unit: Type = syn::parse_quote!{ () };
+        let send_type  = OneshotChannel::get_send_type(lib, &unit);
+        let decl       = OneshotChannel::get_decl(lib, Some(&unit));
+        let async_decl = &impl_vars.async_decl;
+        let recv_call  = match lib {
+            Lib::Std => quote!{ #inter_recv.recv() },
+                   _ => quote!{ #inter_recv.await },
+        };
+
+        // Script Field Struct
+        script_fields.push((variant.clone(),quote!{
+            #variant {
+                #inter_send: std::option::Option<#send_type>,
+            }
+        }));
+
+        // Direct Arm ( the variant is consumed by `play` )
+        direct_arms.push(quote!{
+            #script_name :: #variant {..} => {},
+        });
+
+        // Debug Arm
+        let str_variant = format!("{script_name}::{variant}");
+        debug_arms.push(quote!{
+            #script_name :: #variant {..} => write!(f, #str_variant),
+        });
+
+        live_mets.push((inter_shutdown.clone(),
+            quote!{
+                #new_vis #async_decl fn #inter_shutdown (&self) {
+                    let #msg = #script_name :: #variant { #inter_send: std::option::Option::None };
+                    let _ = #sender_send;
+                }
+            }
+        ));
+
+        live_mets.push((inter_join.clone(),
+            quote!{
+                #new_vis #async_decl fn #inter_join (self) {
+                    let ( #inter_send, #inter_recv ) = #decl;
+                    let #msg = #script_name :: #variant { #inter_send: std::option::Option::Some(#inter_send) };
+                    if #sender_send.is_ok() {
+                        let _ = #recv_call;
+                    }
+                }
+            }
+        ));
+    }
+}
@@ -0,0 +1,74 @@
+use crate::error;
+use crate::model::{ImplVars,Lib,Vars};
+
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::{format_ident,quote};
+use syn::{Meta,Path,Signature};
+
+
+//-----------------------  ACTOR SPAWNER
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Spawner {
+    pub active: bool,
+    pub path:   Option<Path>,
+}
+
+impl Spawner {
+
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    pub fn parse(&mut self, meta: &Meta){
+
+        self.active = true;
+
+        match meta {
+            Meta::Path(_) => (),
+            Meta::NameValue(name_value) => {
+                if let syn::Expr::Path(expr_path) = &name_value.value {
+                    self.path = Some(expr_path.path.clone());
+                } else {
+                    abort!(meta,error::error_name_type( meta.path(), "path::to::function"); help=error::AVAIL_SPAWNER);
+                }
+            },
+            Meta::List(_) => {
+                abort!(meta,error::error_name_type( meta.path(), "path::to::function"); help=error::AVAIL_SPAWNER);
+            },
+        }
+    }
+
+    // the spawn function replacing the library `spawn`
+    pub fn spawn(&self) -> Option<TokenStream> {
+        self.path.as_ref().map(|path| quote!{ #path })
+    }
+
+    // the signature of `new_on`, the `new` function taking
+    // a runtime handle (an executor) as first argument
+    // and the spawn function of the handle
+    pub fn new_on(&self, Vars{ inter_runtime, impl_vars,.. }: &Vars, lib: &Lib, new_sig: &Signature ) -> Option<(Signature,TokenStream)> {
+
+        if !self.active() { return None; }
+
+        let runtime_type = match lib {
+            Lib::Tokio => quote!{ tokio::runtime::Handle },
+            Lib::Smol  => quote!{ &smol::Executor<'_> },
+            Lib::Std   |
+            Lib::AsyncStd => { return None; },
+        };
+
+        let mut sig = new_sig.clone();
+        sig.ident = format_ident!("{}_on",new_sig.ident);
+        sig.inputs.insert(0, syn::parse_quote!{ #inter_runtime: #runtime_type });
+
+        let ImplVars{ actor_methods,.. } = impl_vars;
+        if actor_methods.iter().any(|met| met.get_sig_and_field_name().0.ident == sig.ident) {
+            let msg = error::spawner_new_on_taken(&sig.ident);
+            abort!(new_sig.ident,msg;help=error::AVAIL_SPAWNER);
+        }
+
+        Some((sig, quote!{ #inter_runtime.spawn }))
+    }
+}
@@ -0,0 +1,194 @@
+use crate::error;
+use crate::model::{get_ident_value,get_lit,get_lit_str,get_list,to_usize,ActorMethodNew,ImplVars,Vars};
+
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::quote;
+use syn::{Ident,Meta,Type};
+
+
+//-----------------------  ACTOR SUPERVISE
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub enum Strategy {
+    #[default]
+    OneForOne,
+}
+
+impl Strategy {
+
+    pub fn from( meta: &Meta ) -> Self {
+        match get_lit_str(meta,"strategy").as_str() {
+            "one_for_one" => Strategy::OneForOne,
+            val => {
+                let msg = format!("Unknown option  -  {:?} for 'strategy' ", val);
+                abort!( meta, msg; help=error::AVAIL_SUPERVISE );
+            }
+        }
+    }
+}
+
+
+// a type borrowing data, which can not be moved 
+// into the `'static` factory of the actor
+fn is_borrowed( ty: &Type ) -> bool {
+    match ty {
+        Type::Reference(_) | Type::ImplTrait(_) => true,
+        Type::Paren(ty)  => is_borrowed(&ty.elem),
+        Type::Group(ty)  => is_borrowed(&ty.elem),
+        Type::Array(ty)  => is_borrowed(&ty.elem),
+        Type::Slice(ty)  => is_borrowed(&ty.elem),
+        Type::Tuple(ty)  => ty.elems.iter().any(is_borrowed),
+        Type::TraitObject(ty) => ty.bounds.iter().any(|b| matches!(b, syn::TypeParamBound::Lifetime(l) if l.ident != "static")),
+        Type::Path(ty)   => ty.path.segments.iter().any(|s| match &s.arguments {
+            syn::PathArguments::AngleBracketed(args) => args.args.iter().any(|a| match a {
+                syn::GenericArgument::Type(ty) => is_borrowed(ty),
+                syn::GenericArgument::Lifetime(l) => l.ident != "static",
+                _ => false,
+            }),
+            _ => false,
+        }),
+        _ => false,
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Supervise {
+    pub active:            bool,
+    pub restart:  Option<usize>,
+    pub strategy:      Strategy,
+    pub factory:  Option<Ident>,
+    pub on_restart: Option<Ident>,
+}
+
+impl Supervise {
+
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    pub fn parse(&mut self, meta: &Meta){
+
+        self.active = true;
+
+        if let Some(meta_list) = get_list( meta,Some(error::AVAIL_SUPERVISE) ) {
+
+            crate::model::check_path_set(&meta_list);
+
+            for m in meta_list.iter() {
+
+                if m.path().is_ident("restart"){
+                    match get_lit(m) {
+                        syn::Lit::Int(val) => { self.restart = Some(to_usize(&val)); },
+                        v => abort!(v, error::error_name_type( m.path(), "Int (usize)"); help=error::AVAIL_SUPERVISE ),
+                    }
+                }
+                else if m.path().is_ident("strategy"){
+                    self.strategy = Strategy::from(m);
+                }
+                else if m.path().is_ident("factory"){
+                    self.factory = Some(get_ident_value(m,"factory"));
+                }
+                else if m.path().is_ident("on_restart"){
+                    self.on_restart = Some(get_ident_value(m,"on_restart"));
+                }
+                else {
+                    let msg = "Unknown option for argument 'supervise'.";
+                    abort!(m,msg;help=error::AVAIL_SUPERVISE);
+                }
+            }
+        }
+    }
+
+    // the type of the factory argument of `play`
+    pub fn factory_type(&self, ImplVars{actor_type,..}: &ImplVars ) -> TokenStream {
+        quote!{ std::boxed::Box<dyn Fn() -> std::option::Option<#actor_type> + Send> }
+    }
+
+    // declares the factory in `new`,
+    // cloning the arguments of the original `new` or `try_new`
+    pub fn factory_decl(&self, vars: &Vars, met_new: &ActorMethodNew ) -> TokenStream {
+
+        let Vars{ inter_factory, impl_vars,.. } = vars;
+        let ImplVars{ actor_name,.. } = impl_vars;
+        let factory_type = self.factory_type(impl_vars);
+
+        if let Some(factory) = &self.factory {
+            return quote!{
+                let #inter_factory: #factory_type =
+                std::boxed::Box::new(|| std::option::Option::Some( #actor_name :: #factory () ));
+            };
+        }
+
+        let ActorMethodNew{ new_sig, res_opt,.. } = met_new;
+        let func_new_name = &new_sig.ident;
+
+        let mut idents = Vec::new();
+        for arg in met_new.get_arguments() {
+            if let syn::FnArg::Typed(pat_type) = &arg {
+                // the clones are kept by the factory for the life of the actor
+                if is_borrowed(&pat_type.ty) {
+                    abort!(pat_type.ty, error::SUPERVISE_BORROWED_ARG; help=error::AVAIL_SUPERVISE);
+                }
+                match &*pat_type.pat {
+                    syn::Pat::Ident(pat_ident) => idents.push(pat_ident.ident.clone()),
+                    _ => {
+                        abort!(pat_type, error::SUPERVISE_PATTERN_ARG; help=error::AVAIL_SUPERVISE);
+                    },
+                }
+            }
+        }
+
+        let new_call = quote!{ #actor_name :: #func_new_name ( #(#idents.clone()),* ) };
+        let new_call = match res_opt {
+            Some(true)  => quote!{ #new_call.ok() },
+            Some(false) => quote!{ #new_call },
+            None        => quote!{ std::option::Option::Some( #new_call ) },
+        };
+
+        quote!{
+            let #inter_factory: #factory_type = {
+                #( let #idents = #idents.clone(); )*
+                std::boxed::Box::new(move || #new_call )
+            };
+        }
+    }
+
+    // the body of the `play` loop, 
+    // a rebuilt actor is started again before `on_restart`
+    pub fn play_loop(&self, vars: &Vars, recv_msg: &TokenStream, intercept: &Option<TokenStream>, 
+                     direct_call: &TokenStream, start: &Option<TokenStream> ) -> TokenStream {
+
+        let Vars{ actor, inter_factory, inter_restarts,.. } = vars;
+
+        let restart_limit = self.restart.map(|restart|
+            quote!{
+                if #inter_restarts == #restart {
+                    std::panic::resume_unwind(inter_panic);
+                }
+            }
+        );
+
+        let on_restart = self.on_restart.as_ref().map(|on_restart|
+            quote!{ #actor. #on_restart ( #inter_restarts ); }
+        );
+
+        quote!{
+            let mut #inter_restarts: usize = 0;
+            while let #recv_msg {
+                #intercept
+                let inter_direct = std::panic::AssertUnwindSafe(|| #direct_call );
+                if let std::result::Result::Err(inter_panic) = std::panic::catch_unwind(inter_direct) {
+                    #restart_limit
+                    match #inter_factory () {
+                        std::option::Option::Some(new_actor) => { #actor = new_actor; },
+                        std::option::Option::None => { std::panic::resume_unwind(inter_panic); },
+                    }
+                    #inter_restarts += 1;
+                    #start
+                    #on_restart
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::error;
+use crate::model::{get_lit,get_lit_str,get_list,to_usize,ActorMethodNew,Debut,Vars};
+
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::quote;
+use syn::Meta;
+
+
+//-----------------------  ACTOR THREAD
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Thread {
+    pub active:     bool,
+    pub name:       Option<String>,
+    pub stack_size: Option<usize>,
+}
+
+impl Thread {
+
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    pub fn parse(&mut self, meta: &Meta){
+
+        self.active = true;
+
+        if let Some(meta_list) = get_list( meta,Some(error::AVAIL_THREAD) ) {
+
+            crate::model::check_path_set(&meta_list);
+
+            for m in meta_list.iter() {
+
+                if m.path().is_ident("name"){
+                    self.name = Some(get_lit_str(m,"name"));
+                }
+                else if m.path().is_ident("stack_size"){
+                    match get_lit(m) {
+                        syn::Lit::Int(val) => { self.stack_size = Some(to_usize(&val)); },
+                        v => abort!(v, error::error_name_type( m.path(), "Int (usize)"); help=error::AVAIL_THREAD ),
+                    }
+                }
+                else {
+                    let msg = "Unknown option for argument 'thread'.";
+                    abort!(m,msg;help=error::AVAIL_THREAD);
+                }
+            }
+        }
+    }
+
+    // returns ( the spawn function, the handling of its result ),
+    // a failed spawn is returned by a fallible `new` (`try_new`)
+    pub fn spawn(&self, Vars{ cust_name, debut,.. }: &Vars, debut_opt: &Debut, met_new: &ActorMethodNew ) -> (TokenStream,TokenStream) {
+
+        if !self.active() {
+            return (quote!{ std::thread::spawn }, quote!{});
+        }
+
+        let name = match &self.name {
+            Some(name) => quote!{ .name(#name.to_string()) },
+            // the debut as milliseconds since the epoch, Linux
+            // truncates the name of a thread to 15 bytes
+            None if debut_opt.active() => {
+                let fmt = format!("{cust_name}-{{}}");
+                quote!{
+                    .name(format!(#fmt, #debut.duration_since(std::time::UNIX_EPOCH)
+                        .map(|inter_since| inter_since.as_millis())
+                        .unwrap_or_default()))
+                }
+            },
+            None => {
+                let name = cust_name.to_string();
+                quote!{ .name(#name.to_string()) }
+            },
+        };
+        let stack_size = self.stack_size.map(|size| quote!{ .stack_size(#size) });
+
+        let error = format!("'{cust_name}::new'. Failed to spawn the thread!");
+        let result = match met_new.res_opt {
+            Some(true)  => quote!{ ? },
+            Some(false) => quote!{ .ok()? },
+            None        => quote!{ .expect(#error) },
+        };
+        ( quote!{ std::thread::Builder::new() #name #stack_size .spawn }, result )
+    }
+}
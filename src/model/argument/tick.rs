@@ -0,0 +1,139 @@
+use crate::error;
+use crate::model::{get_duration,get_ident_value,get_list,ChannelImpl,Lib,Vars};
+
+use proc_macro2::TokenStream;
+use proc_macro::Span;
+use proc_macro_error::abort;
+use quote::quote;
+use syn::{Ident,ItemImpl,Meta};
+use std::time::Duration;
+
+
+//-----------------------  ACTOR TICK
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Tick {
+    pub every:  Option<Duration>,
+    pub method: Option<Ident>,
+}
+
+impl Tick {
+
+    pub fn active(&self) -> bool {
+        self.every.is_some()
+    }
+
+    pub fn parse(&mut self, meta: &Meta){
+
+        if let Some(meta_list) = get_list( meta,Some(error::AVAIL_TICK) ) {
+
+            crate::model::check_path_set(&meta_list);
+
+            for m in meta_list.iter() {
+
+                if m.path().is_ident("every"){
+                    let every = get_duration(m,"every");
+                    if every.is_zero() {
+                        abort!(m,error::TICK_ZERO;help=error::AVAIL_TICK);
+                    }
+                    if u64::try_from(every.as_nanos()).is_err() {
+                        abort!(m,error::TICK_RANGE;help=error::AVAIL_TICK);
+                    }
+                    self.every = Some(every);
+                }
+                else if m.path().is_ident("method"){
+                    self.method = Some(get_ident_value(m,"method"));
+                }
+                else {
+                    let msg = "Unknown option for argument 'tick'.";
+                    abort!(m,msg;help=error::AVAIL_TICK);
+                }
+            }
+            if self.every.is_none() || self.method.is_none() {
+                abort!(meta,error::TICK_REQUIRED;help=error::AVAIL_TICK);
+            }
+        } else { abort!(meta,error::EXPECT_LIST;help=error::AVAIL_TICK); }
+    }
+
+    pub fn call(&self, Vars{ actor,.. }: &Vars, item_impl: &ItemImpl ) -> TokenStream {
+        let method = self.method.as_ref().unwrap();
+        crate::model::impl_method_call(item_impl, actor, method, quote!{}, error::AVAIL_TICK)
+    }
+
+    pub fn play_decl(&self, lib: &Lib ) -> TokenStream {
+        let every = self.every_call();
+        match lib {
+            Lib::Tokio => quote!{ let mut inter_tick = tokio::time::Instant::now() + #every; },
+                     _ => quote!{ let mut inter_tick = std::time::Instant::now() + #every; },
+        }
+    }
+
+    fn every_nanos(&self) -> u64 {
+        u64::try_from(self.every.unwrap().as_nanos())
+            .unwrap_or_else(|_| abort!(Span::call_site(),error::TICK_RANGE;help=error::AVAIL_TICK))
+    }
+
+    fn every_call(&self) -> TokenStream {
+        let nanos = self.every_nanos();
+        quote!{ std::time::Duration::from_nanos(#nanos) }
+    }
+
+    // the next message of `play` loop, 
+    // the tick method is called whenever the deadline is passed 
+    pub fn play_recv(&self, Vars{ receiver,.. }: &Vars, lib: &Lib, channel_impl: &ChannelImpl, call: &TokenStream ) -> TokenStream {
+
+        let every = self.every_call();
+        let nanos = u128::from(self.every_nanos());
+        let recv_timeout_error = channel_impl.recv_timeout_error();
+        let (now,recv) = match lib {
+            Lib::Std => (
+                quote!{ std::time::Instant::now() },
+                quote!{
+                    match #receiver.recv_timeout(inter_tick - inter_now) {
+                        std::result::Result::Ok(inter_msg) => break std::option::Option::Some(inter_msg),
+                        std::result::Result::Err(#recv_timeout_error::Timeout) => (),
+                        std::result::Result::Err(_) => break std::option::Option::None,
+                    }
+                }),
+            Lib::Tokio => (
+                quote!{ tokio::time::Instant::now() },
+                quote!{
+                    if let std::result::Result::Ok(inter_msg) = tokio::time::timeout_at(inter_tick, #receiver.recv()).await {
+                        break inter_msg;
+                    }
+                }),
+            Lib::AsyncStd => (
+                quote!{ std::time::Instant::now() },
+                quote!{
+                    if let std::result::Result::Ok(inter_msg) = async_std::future::timeout(inter_tick - inter_now, #receiver.recv()).await {
+                        break inter_msg.ok();
+                    }
+                }),
+            Lib::Smol => (
+                quote!{ std::time::Instant::now() },
+                quote!{
+                    if let std::option::Option::Some(inter_msg) = smol::future::or(
+                        async { std::option::Option::Some(#receiver.recv().await.ok()) },
+                        async { smol::Timer::at(inter_tick).await; std::option::Option::None },
+                    ).await {
+                        break inter_msg;
+                    }
+                }),
+        };
+
+        quote!{
+            loop {
+                let inter_now = #now;
+                if inter_now >= inter_tick {
+                    #call
+                    // missed ticks are skipped, the next deadline 
+                    // is the first one after `inter_now`
+                    let inter_late = (inter_now - inter_tick).as_nanos() % #nanos;
+                    inter_tick = inter_now + #every - std::time::Duration::from_nanos(inter_late as u64);
+                    continue;
+                }
+                #recv
+            }
+        }
+    }
+}
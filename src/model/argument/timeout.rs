@@ -0,0 +1,116 @@
+use crate::error;
+use crate::model::{get_duration,get_list,Lib};
+
+use proc_macro2::{Span,TokenStream};
+use proc_macro_error::abort;
+use quote::quote;
+use syn::{Ident,Meta};
+use syn::ext::IdentExt;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+
+//-----------------------  ACTOR TIMEOUT
+
+// a duration greater than zero that fits
+// in `u64` nanoseconds
+fn get_timeout(meta: &Meta, name: &str) -> Duration {
+    let dur = get_duration(meta,name);
+    if dur.is_zero() {
+        abort!(meta,error::TIMEOUT_ZERO;help=error::AVAIL_TIMEOUT);
+    }
+    if u64::try_from(dur.as_nanos()).is_err() {
+        abort!(meta,error::TIMEOUT_RANGE;help=error::AVAIL_TIMEOUT);
+    }
+    dur
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Timeout {
+    pub default:  Option<Duration>,
+    pub methods:  BTreeMap<Ident,Duration>,
+}
+
+impl Timeout {
+
+    pub fn active(&self) -> bool {
+        self.default.is_some() || !self.methods.is_empty()
+    }
+
+    pub fn parse(&mut self, meta: &Meta){
+
+        match meta {
+            Meta::NameValue(_) => {
+                self.default = Some(get_timeout(meta,"timeout"));
+            },
+            _ => {
+                if let Some(meta_list) = get_list( meta,Some(error::AVAIL_TIMEOUT) ) {
+
+                    crate::model::check_path_set(&meta_list);
+
+                    for m in meta_list.iter() {
+
+                        if m.path().is_ident("default"){
+                            self.default = Some(get_timeout(m,"default"));
+                        }
+                        // a method named `default` is listed as `r#default`
+                        else if let Some(ident) = m.path().get_ident(){
+                            self.methods.insert(ident.unraw(),get_timeout(m,&ident.to_string()));
+                        }
+                        else {
+                            abort!(m,error::EXPECT_IDENT;help=error::AVAIL_TIMEOUT);
+                        }
+                    }
+                } else { abort!(meta,error::EXPECT_LIST;help=error::AVAIL_TIMEOUT); }
+            },
+        }
+    }
+
+    // the timeout of a method if any
+    pub fn get(&self, ident: &Ident) -> Option<Duration> {
+        self.methods.get(ident).cloned().or(self.default)
+    }
+
+    // every method named in the list must be
+    // a method that returns a type
+    pub fn check_methods(&self, timed: &[Ident]){
+        for ident in self.methods.keys(){
+            if !timed.contains(ident){
+                let msg = format!("No method named `{ident}` returning a type in Actor's methods.");
+                abort!(ident,msg;help=error::AVAIL_TIMEOUT);
+            }
+        }
+    }
+
+    pub fn recv_call(&self, lib: &Lib, recv: &Ident, error_name: &Ident, dur: &Duration ) -> TokenStream {
+
+        let nanos = u64::try_from(dur.as_nanos())
+            .unwrap_or_else(|_| abort!(Span::call_site(),error::TIMEOUT_RANGE;help=error::AVAIL_TIMEOUT));
+        let dur = quote!{ std::time::Duration::from_nanos(#nanos) };
+
+        match lib {
+            Lib::Std => quote!{
+                #recv.recv_timeout(#dur).map_err(|error| match error {
+                    oneshot::RecvTimeoutError::Timeout => #error_name :: Timeout,
+                    _ => #error_name :: ReplyDropped,
+                })
+            },
+            Lib::Tokio => quote!{
+                tokio::time::timeout(#dur, #recv).await
+                    .map_err(|_error| #error_name :: Timeout)?
+                    .map_err(|_error| #error_name :: ReplyDropped)
+            },
+            Lib::AsyncStd => quote!{
+                async_std::future::timeout(#dur, #recv).await
+                    .map_err(|_error| #error_name :: Timeout)?
+                    .map_err(|_error| #error_name :: ReplyDropped)
+            },
+            Lib::Smol => quote!{
+                smol::future::or(
+                    async { #recv.await.map_err(|_error| #error_name :: ReplyDropped) },
+                    async { smol::Timer::after(#dur).await; std::result::Result::Err(#error_name :: Timeout) },
+                ).await
+            },
+        }
+    }
+}
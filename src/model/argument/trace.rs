@@ -0,0 +1,147 @@
+use crate::model::{Cont,ImplVars,Lib,Vars};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Ident,Visibility};
+
+
+//-----------------------  ACTOR TRACE
+
+// the message sent by `live` carries the span of the caller,
+// an `enqueue` event is recorded in the caller's span
+pub fn trace_send( Vars{ msg, name, script_name, inter_trace, inter_span, cust_name,.. }: &Vars, debut: bool ) -> TokenStream {
+    let variant   = crate::model::name::script_field(inter_trace);
+    let str_actor = cust_name.to_string();
+    let name_field = if debut { Some(quote!{ name = %self.#name, }) } else { None };
+    quote!{
+        {
+            tracing::debug!( actor = #str_actor, #name_field msg = ?#msg, "enqueue" );
+            #script_name :: #variant { #inter_span: tracing::Span::current(), #msg: std::boxed::Box::new(#msg) }
+        }
+    }
+}
+
+// a `reply` event once the reply is received
+pub fn trace_reply( cust_name: &Ident, recv: TokenStream ) -> TokenStream {
+    let str_actor = cust_name.to_string();
+    quote!{
+        {
+            let inter_reply = #recv;
+            tracing::debug!( actor = #str_actor, "reply" );
+            inter_reply
+        }
+    }
+}
+
+// unwraps the message at the beginning of `play` loop
+pub fn trace_intercept( Vars{ msg, script_name, inter_trace, inter_span, inter_parent,.. }: &Vars ) -> TokenStream {
+    let variant = crate::model::name::script_field(inter_trace);
+    quote!{
+        let (#msg, #inter_parent) = match #msg {
+            #script_name :: #variant { #inter_span, #msg } => (*#msg, std::option::Option::Some(#inter_span)),
+            #msg => (#msg, std::option::Option::None),
+        };
+    }
+}
+
+// the `direct` call within the span of the message
+pub fn trace_play_direct( Vars{ msg, debut, inter_span, inter_parent,.. }: &Vars, lib: &Lib, debut_active: bool, direct_call: TokenStream ) -> TokenStream {
+    let debut_arg = if debut_active { Some(quote!{ , &#debut }) } else { None };
+    let span = quote!{ let #inter_span = #msg.#inter_span ( #inter_parent #debut_arg ); };
+    match lib {
+        Lib::Std => quote!{
+            {
+                #span
+                let _inter_enter = #inter_span.enter();
+                #direct_call;
+            }
+        },
+        _ => quote!{
+            {
+                #span
+                tracing::Instrument::instrument( async { #direct_call; }, #inter_span ).await;
+            }
+        },
+    }
+}
+
+// the span of a message, named after the actor
+// and the method, a child of the caller's span
+pub fn impl_trace(
+    Cont{ script_mets, script_fields, direct_arms, debug_arms,.. }: &mut Cont,
+    Vars{
+        script_name,
+        script_type,
+        cust_name,
+        debut,
+        msg,
+        with,
+        inter_trace,
+        inter_span,
+        inter_parent,
+        impl_vars,..
+    }: &Vars,
+    new_vis: &Option<Visibility>,
+    debut_active: bool,
+){
+    let ImplVars{ actor_methods,.. } = impl_vars;
+    let variant = crate::model::name::script_field(inter_trace);
+
+    // the method names of the variants
+    let mut names = vec![(crate::model::name::script_field(with),with.to_string())];
+    for method in actor_methods {
+        let (sig, field) = method.get_sig_and_field_name();
+        let cast = crate::model::name::cast_method(&sig.ident);
+        names.push((field, sig.ident.to_string()));
+        names.push((crate::model::name::script_field(&cast), cast.to_string()));
+    }
+
+    let debut_field = if debut_active { Some(quote!{ , debut = ?#debut }) } else { None };
+    let debut_param = if debut_active { Some(quote!{ , #debut: &std::time::SystemTime }) } else { None };
+
+    let mut arms = Vec::new();
+    for (field_variant,_) in script_fields.iter() {
+        let method = names.iter()
+            .find(|(name,_)| field_variant.eq(name))
+            .map(|(_,method)| method.clone())
+            .unwrap_or(field_variant.to_string());
+        let str_span = format!("{cust_name}::{method}");
+        arms.push(quote!{
+            #script_name :: #field_variant {..} => tracing::info_span!( parent: #inter_parent, #str_span #debut_field ),
+        });
+    }
+    let str_span = format!("{cust_name}::{variant}");
+    arms.push(quote!{
+        #script_name :: #variant {..} => tracing::info_span!( parent: #inter_parent, #str_span #debut_field ),
+    });
+
+    // Script Field Struct
+    script_fields.push((variant.clone(),quote!{
+        #variant {
+            #inter_span: tracing::Span,
+            #msg: std::boxed::Box<#script_type>,
+        }
+    }));
+
+    // Direct Arm ( the variant is consumed by `play` )
+    direct_arms.push(quote!{
+        #script_name :: #variant {..} => {},
+    });
+
+    // Debug Arm
+    let str_variant = format!("{script_name}::{variant}");
+    debug_arms.push(quote!{
+        #script_name :: #variant {..} => write!(f, #str_variant),
+    });
+
+    script_mets.push((inter_span.clone(),
+        quote!{
+            #new_vis fn #inter_span (&self, #inter_parent: std::option::Option<tracing::Span> #debut_param ) -> tracing::Span {
+                let #inter_parent = #inter_parent.as_ref().and_then(|span| span.id());
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    ));
+}
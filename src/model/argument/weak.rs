@@ -0,0 +1,149 @@
+use crate::model::{ActorAttributeArguments,Channel,Cont,Lib,MpscChannel,Vars};
+
+use proc_macro2::TokenStream;
+use quote::{quote,format_ident};
+use syn::{Ident,Visibility,TypeGenerics,WhereClause};
+
+
+//-----------------------  ACTOR WEAK
+
+// `tokio` senders have weak counterparts, for other
+// libraries the `live` instances share a sender through
+// an `Arc`, the last one dropped closes the channel
+fn has_weak_sender( lib: &Lib ) -> bool {
+    Lib::Tokio.eq(lib)
+}
+
+// the type of the live `sender` field
+fn sender_type( ActorAttributeArguments{ pool,.. }: &ActorAttributeArguments, MpscChannel{ type_sender,.. }: &MpscChannel ) -> TokenStream {
+    if pool.active(){ quote!{ std::vec::Vec<#type_sender> } } else { type_sender.clone() }
+}
+
+// the live field keeping the channel open
+pub fn weak_live_field( Vars{ inter_alive,.. }: &Vars, aaa: &ActorAttributeArguments, mpsc: &MpscChannel ) -> Option<TokenStream> {
+    if has_weak_sender(&aaa.lib){ return None; }
+    let sender_type = sender_type(aaa,mpsc);
+    Some(quote!{ pub #inter_alive: std::sync::Arc<#sender_type>, })
+}
+
+// declared in `new`
+pub fn weak_decl( Vars{ sender, inter_alive,.. }: &Vars, aaa: &ActorAttributeArguments ) -> Option<TokenStream> {
+    if has_weak_sender(&aaa.lib){ return None; }
+    Some(quote!{ let #inter_alive = std::sync::Arc::new(#sender.clone()); })
+}
+
+// the fields cloned as they are
+fn cloned_fields( aaa: &ActorAttributeArguments ) -> Vec<Ident> {
+    let skip = [format_ident!("sender"),format_ident!("debut"),format_ident!("inter_alive")];
+    aaa.get_inter_field_names().into_iter().filter(|x| !skip.contains(x)).collect()
+}
+
+// the definition of weak live,
+// `fields` are the live fields cloned by `downgrade`
+pub fn weak_def(
+    Vars{ sender, debut, inter_alive, weak_name, script_type,.. }: &Vars,
+    aaa:          &ActorAttributeArguments,
+    mpsc:         &MpscChannel,
+    fields:       &TokenStream,
+    new_vis:      &Option<Visibility>,
+    ty_generics:  &TypeGenerics,
+    where_clause: &Option<&WhereClause>,
+) -> TokenStream {
+
+    let sender_field =
+    if has_weak_sender(&aaa.lib){
+        let weak_sender = match aaa.channel {
+            Channel::Unbounded => quote!{ tokio::sync::mpsc::WeakUnboundedSender<#script_type> },
+            Channel::Buffer(_) => quote!{ tokio::sync::mpsc::WeakSender<#script_type> },
+        };
+        if aaa.pool.active(){ quote!{ #sender: std::vec::Vec<#weak_sender>, } }
+        else { quote!{ #sender: #weak_sender, } }
+    } else {
+        let sender_type = sender_type(aaa,mpsc);
+        quote!{ pub #inter_alive: std::sync::Weak<#sender_type>, }
+    };
+
+    // `inter_get_count` counts the live instances only
+    let debut_field =
+    if aaa.debut.active(){ Some(quote!{ pub #debut: std::sync::Weak<std::time::SystemTime>, }) } else { None };
+
+    quote!{
+        #[derive(Clone)]
+        #new_vis struct #weak_name #ty_generics #where_clause {
+            #sender_field
+            #debut_field
+            #fields
+        }
+    }
+}
+
+// `downgrade` live method and `upgrade` weak live method
+pub fn impl_weak(
+    Cont{ live_mets, live_trts,.. }: &mut Cont,
+    Vars{ sender, debut, inter_alive, live_name, weak_name, downgrade, upgrade,.. }: &Vars,
+    aaa:          &ActorAttributeArguments,
+    new_vis:      &Option<Visibility>,
+    ty_generics:  &TypeGenerics,
+    where_clause: &Option<&WhereClause>,
+){
+    let fields = cloned_fields(aaa);
+
+    let (down_sender, up_sender) =
+    if has_weak_sender(&aaa.lib){
+        if aaa.pool.active(){
+            ( quote!{ #sender: self.#sender.iter().map(|inter_sender| inter_sender.downgrade()).collect(), },
+              quote!{
+                let #sender = self.#sender.iter()
+                    .map(|inter_sender| inter_sender.upgrade())
+                    .collect::<std::option::Option<std::vec::Vec<_>>>()?;
+              })
+        } else {
+            ( quote!{ #sender: self.#sender.downgrade(), },
+              quote!{ let #sender = self.#sender.upgrade()?; })
+        }
+    } else {
+        ( quote!{ #inter_alive: std::sync::Arc::downgrade(&self.#inter_alive), },
+          quote!{
+            let #inter_alive = self.#inter_alive.upgrade()?;
+            let #sender = (*#inter_alive).clone();
+          })
+    };
+    let alive_init = if has_weak_sender(&aaa.lib){ None } else { Some(quote!{ #inter_alive, }) };
+
+    let (down_debut, up_debut, debut_init) =
+    if aaa.debut.active(){
+        ( Some(quote!{ #debut: std::sync::Arc::downgrade(&self.#debut), }),
+          Some(quote!{ let #debut = self.#debut.upgrade()?; }),
+          Some(quote!{ #debut, }) )
+    } else { (None,None,None) };
+
+    live_mets.push((downgrade.clone(),
+        quote!{
+            #new_vis fn #downgrade (&self) -> #weak_name #ty_generics {
+                #weak_name {
+                    #down_sender
+                    #down_debut
+                    #( #fields: self.#fields.clone(), )*
+                }
+            }
+        }
+    ));
+
+    // the actor is gone once every live instance is dropped
+    live_trts.push((weak_name.clone(),
+        quote!{
+            impl #ty_generics #weak_name #ty_generics #where_clause {
+                #new_vis fn #upgrade (&self) -> std::option::Option<#live_name #ty_generics> {
+                    #up_sender
+                    #up_debut
+                    std::option::Option::Some( #live_name {
+                        #sender,
+                        #alive_init
+                        #debut_init
+                        #( #fields: self.#fields.clone(), )*
+                    })
+                }
+            }
+        }
+    ));
+}
@@ -0,0 +1,85 @@
+use crate::model::{ActorAttributeArguments,Cont,MpscChannel,OneshotChannel,Vars};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Visibility;
+
+
+//-----------------------  ACTOR WITH
+
+// `with` and `with_mut` live methods running a closure on the actor,
+// the closure is boxed into the script variant and 
+// replies through a `oneshot` channel 
+pub fn impl_with(
+    Cont{
+        live_mets,
+        direct_arms,
+        debug_arms,
+        script_fields,..
+    }: &mut Cont,
+    Vars{
+        actor,
+        script_name,
+        cust_name,
+        error_name,
+        with,
+        with_mut,
+        inter_fn,
+        inter_send,
+        msg,
+        impl_vars,..
+    }: &Vars,
+    aaa:                          &ActorAttributeArguments,
+    new_vis:                 &Option<Visibility>,
+    oneshot:                       &OneshotChannel,
+    MpscChannel{ sender_call, sender_call_err,.. }: &MpscChannel,
+){
+    let variant    = crate::model::name::script_field(with);
+    let actor_type = &impl_vars.actor_type;
+    let async_decl = &impl_vars.async_decl;
+    let decl       = oneshot.decl(None);
+
+    // Script Field Struct
+    script_fields.push((variant.clone(),quote!{
+        #variant {
+            #inter_fn: Box<dyn FnOnce(&mut #actor_type) + Send>,
+        }
+    }));
+
+    // Direct Arm
+    direct_arms.push(quote!{
+        #script_name :: #variant { #inter_fn } => { #inter_fn (#actor); },
+    });
+
+    // Debug Arm
+    let str_variant = format!("{script_name}::{variant}");
+    debug_arms.push(quote!{
+        #script_name :: #variant {..} => write!(f, #str_variant),
+    });
+
+    // Live Methods
+    let (sender_call,output) = 
+    if aaa.fallible {
+        ( sender_call_err, quote!{ std::result::Result<InterOutput, #error_name> })
+    } else { ( sender_call, quote!{ InterOutput }) };
+
+    let live_met = |ident: &syn::Ident, recv: TokenStream, refer: TokenStream| -> TokenStream {
+        let recv_output = 
+        if aaa.fallible { oneshot.recv_call_err(error_name) } 
+        else { oneshot.recv_call(cust_name,ident) };
+        quote!{
+            #new_vis #async_decl fn #ident <InterOutput: Send + 'static> 
+            (#recv, f: impl FnOnce(#refer #actor_type) -> InterOutput + Send + 'static) -> #output {
+                #decl
+                let #msg = #script_name :: #variant { 
+                    #inter_fn: Box::new(move |#actor: &mut #actor_type| { let _ = #inter_send.send(f(#actor)); })
+                };
+                #sender_call
+                #recv_output
+            }
+        }
+    };
+
+    live_mets.push((with.clone(), live_met(with, quote!{ &self }, quote!{ & })));
+    live_mets.push((with_mut.clone(), live_met(with_mut, quote!{ &mut self }, quote!{ &mut })));
+}
@@ -1,5 +1,5 @@
 use crate::error;
-use crate::model::{Channel,Lib,EditActor,Debut,get_ident,get_lit,get_lit_str,get_list,to_usize};
+use crate::model::{Channel,ChannelImpl,Lib,EditActor,Debut,Supervise,Shutdown,Timeout,MethodList,Priority,Tick,Hooks,Pool,Events,OnEnd,Serde,Thread,Spawner,get_ident,get_lit,get_lit_str,get_list,to_usize};
 
 
 use std::path::PathBuf;
@@ -22,6 +22,30 @@ pub struct ActorAttributeArguments {
     pub file    :  Option<PathBuf>,
     pub path    :  Option<PathBuf>,
     pub interact:  bool,
+    pub fallible:  bool,
+    pub supervise: Supervise,
+    pub shutdown:  Shutdown,
+    pub handle:    bool,
+    pub try_send:  bool,
+    pub timeout:   Timeout,
+    pub cast:      MethodList,
+    pub deferred:  MethodList,
+    pub with:      bool,
+    pub priority:  Priority,
+    pub tick:      Tick,
+    pub hooks:     Hooks,
+    pub pool:      Pool,
+    pub events:    Events,
+    pub weak:      bool,
+    pub registry:  bool,
+    pub metrics:   bool,
+    pub trace:     bool,
+    pub on_end:    OnEnd,
+    pub serde:     Serde,
+    pub remote:    bool,
+    pub channel_impl: ChannelImpl,
+    pub thread:    Thread,
+    pub spawner:   Spawner,
 
     /* ADD NEW OPTION */
 }
@@ -41,6 +65,30 @@ impl Default for ActorAttributeArguments {
             file    : None,
             path    : None,
             interact: false,
+            fallible: false,
+            supervise: Supervise::default(),
+            shutdown: Shutdown::default(),
+            handle: false,
+            try_send: false,
+            timeout: Timeout::default(),
+            cast: MethodList::default(),
+            deferred: MethodList::default(),
+            with: false,
+            priority: Priority::default(),
+            tick: Tick::default(),
+            hooks: Hooks::default(),
+            pool: Pool::default(),
+            events: Events::default(),
+            weak: false,
+            registry: false,
+            metrics: false,
+            trace: false,
+            on_end: OnEnd::default(),
+            serde: Serde::default(),
+            remote: false,
+            channel_impl: ChannelImpl::default(),
+            thread: Thread::default(),
+            spawner: Spawner::default(),
             /* ADD NEW ATTRIBUTE */
         }  
     }
@@ -101,6 +149,11 @@ impl ActorAttributeArguments {
                 }
             }
 
+            // CHANNEL_IMPL
+            else if meta.path().is_ident("channel_impl"){
+                self.channel_impl = ChannelImpl::from(meta);
+            }
+
 
             // EDIT
             else if meta.path().is_ident(crate::EDIT){
@@ -144,6 +197,148 @@ impl ActorAttributeArguments {
                 }
             }
 
+            // FALLIBLE
+            else if meta.path().is_ident("fallible"){
+                match meta {
+                    syn::Meta::Path(_) => { self.fallible = true; },
+                    _ => { abort!(meta, error::EXPECT_IDENT ;help=error::AVAIL_ACTOR) },
+                }
+            }
+
+            // SUPERVISE
+            else if meta.path().is_ident("supervise"){
+                self.supervise.parse(meta);
+            }
+
+            // SHUTDOWN
+            else if meta.path().is_ident("shutdown"){
+                self.shutdown.parse(meta);
+            }
+
+            // HANDLE
+            else if meta.path().is_ident("handle"){
+                match meta {
+                    syn::Meta::Path(_) => { self.handle = true; },
+                    _ => { abort!(meta, error::EXPECT_IDENT ;help=error::AVAIL_ACTOR) },
+                }
+            }
+
+            // TRY_SEND
+            else if meta.path().is_ident("try_send"){
+                match meta {
+                    syn::Meta::Path(_) => { self.try_send = true; },
+                    _ => { abort!(meta, error::EXPECT_IDENT ;help=error::AVAIL_ACTOR) },
+                }
+            }
+
+            // TIMEOUT
+            else if meta.path().is_ident("timeout"){
+                self.timeout.parse(meta);
+            }
+
+            // CAST
+            else if meta.path().is_ident("cast"){
+                self.cast.parse(meta,error::AVAIL_CAST);
+            }
+
+            // DEFERRED
+            else if meta.path().is_ident("deferred"){
+                self.deferred.parse(meta,error::AVAIL_DEFERRED);
+            }
+
+            // PRIORITY
+            else if meta.path().is_ident("priority"){
+                self.priority.parse(meta);
+            }
+
+            // TICK
+            else if meta.path().is_ident("tick"){
+                self.tick.parse(meta);
+            }
+
+            // HOOKS
+            else if meta.path().is_ident("hooks"){
+                self.hooks.parse(meta);
+            }
+
+            // POOL
+            else if meta.path().is_ident("pool"){
+                self.pool.parse(meta);
+            }
+
+            // SERDE
+            else if meta.path().is_ident("serde"){
+                self.serde.parse(meta);
+            }
+
+            // THREAD
+            else if meta.path().is_ident("thread"){
+                self.thread.parse(meta);
+            }
+
+            // SPAWNER
+            else if meta.path().is_ident("spawner"){
+                self.spawner.parse(meta);
+            }
+
+            // REMOTE
+            else if meta.path().is_ident("remote"){
+                match meta {
+                    syn::Meta::Path(_) => { self.remote = true; },
+                    _ => { abort!(meta, error::EXPECT_IDENT ;help=error::AVAIL_ACTOR) },
+                }
+            }
+
+            // ON_END
+            else if meta.path().is_ident("on_end"){
+                self.on_end = OnEnd::from(meta);
+            }
+
+            // EVENTS
+            else if meta.path().is_ident("events"){
+                self.events.parse(meta);
+            }
+
+            // WITH
+            else if meta.path().is_ident("with"){
+                match meta {
+                    syn::Meta::Path(_) => { self.with = true; },
+                    _ => { abort!(meta, error::EXPECT_IDENT ;help=error::AVAIL_ACTOR) },
+                }
+            }
+
+            // WEAK
+            else if meta.path().is_ident("weak"){
+                match meta {
+                    syn::Meta::Path(_) => { self.weak = true; },
+                    _ => { abort!(meta, error::EXPECT_IDENT ;help=error::AVAIL_ACTOR) },
+                }
+            }
+
+            // METRICS
+            else if meta.path().is_ident("metrics"){
+                match meta {
+                    syn::Meta::Path(_) => { self.metrics = true; },
+                    _ => { abort!(meta, error::EXPECT_IDENT ;help=error::AVAIL_ACTOR) },
+                }
+            }
+
+            // REGISTRY
+            else if meta.path().is_ident("registry"){
+                match meta {
+                    syn::Meta::Path(_) => { self.registry = true; },
+                    _ => { abort!(meta, error::EXPECT_IDENT ;help=error::AVAIL_ACTOR) },
+                }
+            }
+
+            // TRACE
+            else if meta.path().is_ident("trace"){
+                match meta {
+                    syn::Meta::Path(_) => { self.trace = true; },
+                    _ => { abort!(meta, error::EXPECT_IDENT ;help=error::AVAIL_ACTOR) },
+                }
+            }
+
             else if meta.path().is_ident("debug") {
                 abort!(meta,"Did you mean `debut`?"; help=error::AVAIL_ACTOR);
             }
@@ -170,6 +365,102 @@ impl ActorAttributeArguments {
                 }
             } else { abort!(Span::call_site(),error::REQ_FILE;help=error::AVAIL_ACTOR); }
         }
+
+        // handle
+        if self.handle && self.debut.is_legend() {
+            abort!(Span::call_site(),error::HANDLE_LEGEND;help=error::AVAIL_ACTOR);
+        }
+
+        // try_send
+        if self.try_send && Channel::Unbounded.eq(&self.channel) {
+            abort!(Span::call_site(),error::TRY_SEND_UNBOUNDED;help=error::AVAIL_ACTOR);
+        }
+
+        // priority
+        if self.priority.active() && self.debut.is_legend() {
+            abort!(Span::call_site(),error::PRIORITY_LEGEND;help=error::AVAIL_PRIORITY);
+        }
+
+        // pool
+        if self.pool.active() && 
+          ( self.handle || self.shutdown.active() || self.priority.active() || 
+            self.supervise.active() || self.interact || self.debut.is_legend() ||
+            self.try_send || self.tick.active() || self.with || self.deferred.active() ){
+            abort!(Span::call_site(),error::POOL_COMBINE;help=error::AVAIL_POOL);
+        }
+
+        // weak
+        if self.weak && self.debut.is_legend() {
+            abort!(Span::call_site(),error::WEAK_LEGEND;help=error::AVAIL_ACTOR);
+        }
+
+        // hooks
+        if self.hooks.stop.is_some() && self.shutdown.on_stop.is_some() {
+            abort!(Span::call_site(),error::HOOKS_STOP;help=error::AVAIL_HOOKS);
+        }
+
+        // metrics
+        if self.metrics && ( self.priority.active() || self.debut.is_legend() ) {
+            abort!(Span::call_site(),error::METRICS_PRIORITY;help=error::AVAIL_ACTOR);
+        }
+
+        // registry
+        if self.registry && self.debut.is_legend() {
+            abort!(Span::call_site(),error::REGISTRY_LEGEND;help=error::AVAIL_ACTOR);
+        }
+
+        // serde
+        if self.serde.active() && ( self.with || self.trace || self.interact ) {
+            abort!(Span::call_site(),error::SERDE_COMBINE;help=error::AVAIL_SERDE);
+        }
+
+        // channel_impl
+        if self.channel_impl.active() && !Lib::Std.eq(&self.lib) {
+            abort!(Span::call_site(),error::CHANNEL_IMPL_LIB;help=error::AVAIL_CHANNEL_IMPL);
+        }
+
+        // thread
+        if self.thread.active() && !Lib::Std.eq(&self.lib) {
+            abort!(Span::call_site(),error::THREAD_LIB;help=error::AVAIL_THREAD);
+        }
+
+        // spawner
+        if self.spawner.active() && Lib::Std.eq(&self.lib) {
+            abort!(Span::call_site(),error::SPAWNER_LIB;help=error::AVAIL_SPAWNER);
+        }
+        if self.spawner.active() && self.spawner.path.is_none() && Lib::AsyncStd.eq(&self.lib) {
+            abort!(Span::call_site(),error::SPAWNER_ASYNC_STD;help=error::AVAIL_SPAWNER);
+        }
+
+        // remote
+        if self.remote && !self.serde.active() {
+            abort!(Span::call_site(),error::REMOTE_SERDE;help=error::AVAIL_SERDE);
+        }
+
+        // events
+        if self.events.active() && !self.interact {
+            abort!(Span::call_site(),error::EVENTS_INTERACT;help=error::AVAIL_EVENTS);
+        }
+
+        // deferred
+        if self.deferred.active() && Lib::Std.eq(&self.lib) {
+            abort!(Span::call_site(),error::DEFERRED_LIB;help=error::AVAIL_DEFERRED);
+        }
+
+        // supervise
+        if self.supervise.active(){
+            if !Lib::Std.eq(&self.lib) {
+                abort!(Span::call_site(),error::SUPERVISE_LIB;help=error::AVAIL_SUPERVISE);
+            }
+            if self.debut.is_legend() {
+                abort!(Span::call_site(),error::SUPERVISE_LEGEND;help=error::AVAIL_SUPERVISE);
+            }
+        }
+    }
+
+    // the model declares an error type 
+    pub fn has_error_type(&self) -> bool {
+        self.fallible || self.try_send || self.timeout.active()
     }
 
     pub fn get_inter_field_names(&self) -> Vec<Ident> {
@@ -178,6 +469,27 @@ impl ActorAttributeArguments {
             loc.push(format_ident!("debut"));
             loc.push(format_ident!("name"));
         }
+        if self.handle {
+            loc.push(format_ident!("handle"));
+        }
+        if self.priority.active() {
+            loc.push(format_ident!("sender_high"));
+        }
+        if self.pool.active() {
+            loc.push(format_ident!("inter_pool"));
+        }
+        if self.events.active() {
+            loc.push(format_ident!("inter_events"));
+        }
+        if self.weak && !Lib::Tokio.eq(&self.lib) {
+            loc.push(format_ident!("inter_alive"));
+        }
+        if self.registry {
+            loc.push(format_ident!("inter_registry"));
+        }
+        if self.metrics {
+            loc.push(format_ident!("inter_metrics"));
+        }
         loc
     }   
 
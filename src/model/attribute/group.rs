@@ -12,7 +12,7 @@ use syn::{Ident,ItemStruct,ItemImpl,Visibility,Type,punctuated::Punctuated,Meta}
 use std::collections::BTreeMap;
 
 use super::ActorAttributeArguments;
-use crate::model::Debut;
+use crate::model::{Debut,Shutdown};
 
 
 #[derive(Clone)]
@@ -22,6 +22,10 @@ pub struct GroupAttributeArguments {
     pub lib     :  Lib,
     pub file    :  Option<PathBuf>,
     pub debut   :  Debut,
+    pub fallible:  bool,
+    pub shutdown:  Shutdown,
+    pub handle:    bool,
+    pub try_send:  bool,
 
     pub name    :  BTreeMap<Ident,Ident>,
     pub assoc   :  Option<BTreeMap<Ident,bool>>,
@@ -89,6 +93,35 @@ impl GroupAttributeArguments {
                 } else {  self.debut.legend = Some(false);  }
             }
             
+            // FALLIBLE
+            else if meta.path().is_ident("fallible"){
+                match meta {
+                    syn::Meta::Path(_) => { self.fallible = true; },
+                    _ => { abort!(meta, error::EXPECT_IDENT ;help=error::AVAIL_GROUP) },
+                }
+            }
+
+            // SHUTDOWN
+            else if meta.path().is_ident("shutdown"){
+                self.shutdown.parse(meta);
+            }
+
+            // HANDLE
+            else if meta.path().is_ident("handle"){
+                match meta {
+                    syn::Meta::Path(_) => { self.handle = true; },
+                    _ => { abort!(meta, error::EXPECT_IDENT ;help=error::AVAIL_GROUP) },
+                }
+            }
+
+            // TRY_SEND
+            else if meta.path().is_ident("try_send"){
+                match meta {
+                    syn::Meta::Path(_) => { self.try_send = true; },
+                    _ => { abort!(meta, error::EXPECT_IDENT ;help=error::AVAIL_GROUP) },
+                }
+            }
+            
             // NAME
             else if meta.path().is_ident("name"){
 
@@ -288,6 +321,15 @@ impl GroupAttributeArguments {
 
         } else { abort!(Span::call_site(),error::REQ_FILE;help=error::AVAIL_ACTOR); }
 
+        // handle
+        if self.handle && self.debut.is_legend() {
+            abort!(Span::call_site(),error::HANDLE_LEGEND;help=error::AVAIL_GROUP);
+        }
+
+        // try_send
+        if self.try_send && Channel::Unbounded.eq(&self.channel) {
+            abort!(Span::call_site(),error::TRY_SEND_UNBOUNDED;help=error::AVAIL_GROUP);
+        }
     }
 
 
@@ -299,7 +341,13 @@ impl GroupAttributeArguments {
         aaa.channel = self.channel.clone();
         aaa.lib = self.lib.clone();
         aaa.file = self.file.clone();
-        if fld.is_none(){ aaa.debut = self.debut.clone();}
+        aaa.fallible = self.fallible;
+        aaa.try_send = self.try_send;
+        if fld.is_none(){ 
+            aaa.debut = self.debut.clone();
+            aaa.shutdown = self.shutdown.clone();
+            aaa.handle = self.handle;
+        }
 
         aaa.name = self.name.get(slf).cloned();
         aaa.path = self.path.get(slf).cloned();
@@ -345,6 +393,10 @@ impl Default for GroupAttributeArguments {
             lib     :  Lib::default(),
             file    :  None,
             debut   :  Debut::default(),
+            fallible:  false,
+            shutdown:  Shutdown::default(),
+            handle:    false,
+            try_send:  false,
 
             name    :  BTreeMap::new(),
             assoc   :  None,
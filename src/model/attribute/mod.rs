@@ -15,6 +15,8 @@ use proc_macro_error::abort;
 use quote::quote;
 
 
+// a single value per macro invocation
+#[allow(clippy::large_enum_variant)]
 pub enum AttributeArguments {
     Actor(ActorAttributeArguments),
     Group(GroupAttributeArguments),
@@ -117,6 +119,46 @@ pub fn get_lit_str( meta: &syn::Meta ,arg: &str ) -> String {
 }
 
 
+pub fn get_duration( meta: &syn::Meta, arg: &str ) -> std::time::Duration {
+    match get_lit(meta) {
+        syn::Lit::Str(val) => {
+            parse_duration(&val.value())
+                .unwrap_or_else(|| abort!(val, format!("Unexpected duration for '{arg}'."); help=error::DURATION_FORMAT))
+        },
+        v => abort!(v, error::error_name_type( meta.path(), "str"); help=error::DURATION_FORMAT ),
+    }
+}
+
+// `arg = method_name` or `arg = "method_name"`
+pub fn get_ident_value( meta: &syn::Meta, arg: &str ) -> syn::Ident {
+    if let syn::Meta::NameValue(name_value) = meta {
+        if let syn::Expr::Path(expr_path) = &name_value.value {
+            if let Some(ident) = expr_path.path.get_ident(){
+                return ident.clone();
+            }
+            abort!(expr_path,error::EXPECT_IDENT);
+        }
+    }
+    quote::format_ident!("{}",get_lit_str(meta,arg))
+}
+
+// "250ms" -> Duration
+fn parse_duration( s: &str ) -> Option<std::time::Duration> {
+    let s = s.trim();
+    let pos = s.find(|c: char| !c.is_ascii_digit())?;
+    let (value, unit) = s.split_at(pos);
+    let value = value.parse::<u64>().ok()?;
+    match unit.trim() {
+        "ns" => Some(std::time::Duration::from_nanos(value)),
+        "us" => Some(std::time::Duration::from_micros(value)),
+        "ms" => Some(std::time::Duration::from_millis(value)),
+        "s"  => Some(std::time::Duration::from_secs(value)),
+        "m"  => Some(std::time::Duration::from_secs(value.checked_mul(60)?)),
+        "h"  => Some(std::time::Duration::from_secs(value.checked_mul(3600)?)),
+        _    => None,
+    }
+}
+
 pub fn get_lit( meta: &syn::Meta ) -> syn::Lit {
 
     let msg = "Expected a 'name = value' argument !";
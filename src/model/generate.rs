@@ -44,7 +44,7 @@ fn group_direct_add_fields(cont: &mut Cont, vars: &Vars, model_sdpl: &ModelSdpl
         let script_field = quote! { 
             #variant_name ( #field_struct_variant_name #mem_model_generics )
         };
-        script_fields.push(script_field);
+        script_fields.push((variant_name.clone(),script_field));
 
         // Debug arm
         let str_field_name = format!("{}::{}",script_name.to_string() ,field.to_string());
@@ -272,7 +272,9 @@ pub fn generate_model( aa: AttributeArguments, item_impl: &ItemImpl , impl_vars:
         debut, msg,  debut_play,
         sender,receiver,name,
         impl_vars,script_name,live_name,
-        cust_name, .. } = vars;
+        cust_name, inter_factory,
+        handle, inter_take_handle, task_name,
+        sender_high, receiver_high, inter_pool, inter_events, inter_alive, inter_registry, inter_metrics, .. } = vars;
 
     let ImplVars { 
         vis,model_generics,
@@ -318,18 +320,61 @@ pub fn generate_model( aa: AttributeArguments, item_impl: &ItemImpl , impl_vars:
         let unwrapped          = met_new.unwrap_sign();
         let vis                = &met_new.vis.clone();
         let group_fields_init = model_sdpl.get_fields_init();
-        let (init_live, play_args) = {
+        let handle_init = if aaa.handle { quote!{ ,#handle } } else { quote!{} };
+        let high_init   = if aaa.priority.active() { quote!{ ,#sender_high } } else { quote!{} };
+        let pool_init   = if aaa.pool.active() { quote!{ ,#inter_pool } } else { quote!{} };
+        let events_init = if aaa.events.active() { quote!{ ,#inter_events } } else { quote!{} };
+        let metrics_init = if aaa.metrics { quote!{ ,#inter_metrics } } else { quote!{} };
+        let registry_init = if aaa.registry { quote!{ ,#inter_registry } } else { quote!{} };
+        let weak_init   = if aaa.weak && !Lib::Tokio.eq(&aaa.lib) { quote!{ ,#inter_alive } } else { quote!{} };
+        let (init_live, mut play_args) = {
             if aaa.debut.active() {
-                (quote!{ Self { #group_fields_init #debut: std::sync::Arc::clone(&#debut), #name : format!("{:?}",* #debut),#sender  }} ,
+                (quote!{ Self { #group_fields_init #debut: std::sync::Arc::clone(&#debut), #name : format!("{:?}",* #debut),#sender #handle_init #high_init #pool_init #events_init #weak_init #registry_init #metrics_init }} ,
                     quote!{ #receiver, #actor, #debut_play})
             } else {
 
-                (quote!{ Self{ #group_fields_init #sender  } }, 
+                (quote!{ Self{ #group_fields_init #sender #handle_init #high_init #pool_init #events_init #weak_init #registry_init #metrics_init } }, 
                     quote!{ #receiver, #actor } )
             }
         };
 
-        let spawn = aaa.lib.method_new_spawn(&play_args,script_name);
+        let vars_factory = 
+        if aaa.supervise.active() {
+            play_args = quote!{ #play_args, #inter_factory };
+            aaa.supervise.factory_decl(vars,&met_new)
+        } else {quote!{}};
+
+        let high_decl = 
+        if aaa.priority.active() {
+            play_args = quote!{ #play_args, #receiver_high };
+            Some(aaa.priority.lane(vars,&aaa.lib).2)
+        } else { None };
+
+        let events_decl = 
+        if aaa.events.active() { Some(aaa.events.decl(vars,&aaa.lib)) } else { None };
+
+        let weak_decl = 
+        if aaa.weak { crate::model::weak_decl(vars,&aaa) } else { None };
+
+        if let Some((pool_arg,_)) = aaa.pool.play_arg(vars) {
+            play_args = quote!{ #play_args #pool_arg };
+        }
+
+        let registry_decl = 
+        if aaa.registry {
+            play_args = quote!{ #play_args, #inter_registry };
+            Some(crate::model::registry_decl(vars))
+        } else { None };
+
+        let metrics_decl = 
+        if aaa.metrics {
+            let (metrics_arg,_) = crate::model::metrics_play_arg(vars);
+            play_args = quote!{ #play_args #metrics_arg };
+            Some(crate::model::metrics_decl(vars))
+        } else { None };
+
+        let thread_spawn = aaa.thread.spawn(vars, &aaa.debut, &met_new);
+        let spawner = aaa.spawner.spawn();
         let turbofish = s_ty_generics.as_turbofish();
 
         let vars_debut = 
@@ -342,19 +387,69 @@ pub fn generate_model( aa: AttributeArguments, item_impl: &ItemImpl , impl_vars:
         
         let MpscChannel{declaration, ..} = mpsc;
         let Cont{live_mets,..} = &mut cont;
-        
-        let func_new_body = quote!{
-
-            #vis #new_sig {
-                let #actor = #actor_name:: #func_new_name #args_ident #unwrapped;
-                #declaration
-                #vars_debut
-                #spawn
-                #return_statement
+
+        let spawn_with = |spawner: Option<&TokenStream>| {
+            let spawn = 
+            if aaa.handle {
+                aaa.lib.method_new_spawn_handle(&play_args,script_name,handle,task_name,&thread_spawn,spawner)
+            } else {
+                aaa.lib.method_new_spawn(&play_args,script_name,&thread_spawn,spawner)
+            };
+            if aaa.metrics { crate::model::metrics_spawn(vars, spawn) } else { spawn }
+        };
+        let spawn = spawn_with(spawner.as_ref());
+
+        let new_body = |new_sig: &syn::Signature, spawn: &TokenStream| {
+            if aaa.pool.active() {
+                let pool_spawn = aaa.pool.new_spawn(vars, &met_new, impl_vars, declaration, &vars_debut, spawn);
+                quote!{
+                    #vis #new_sig {
+                        #events_decl
+                        #registry_decl
+                        #metrics_decl
+                        #pool_spawn
+                        #weak_decl
+                        #return_statement
+                    }
+                }
+            } else {
+                quote!{
+
+                    #vis #new_sig {
+                        #vars_factory
+                        let #actor = #actor_name:: #func_new_name #args_ident #unwrapped;
+                        #declaration
+                        #high_decl
+                        #events_decl
+                        #registry_decl
+                        #metrics_decl
+                        #vars_debut
+                        #spawn
+                        #weak_decl
+                        #return_statement
+                    }
+                }
             }
         };
 
-        live_mets.insert(0,(new_sig.ident.clone(),func_new_body));
+        live_mets.insert(0,(new_sig.ident.clone(),new_body(new_sig,&spawn)));
+
+        // NEW ON
+        if let Some((new_on_sig,runtime_spawn)) = aaa.spawner.new_on(vars, &aaa.lib, new_sig) {
+            live_mets.insert(1,(new_on_sig.ident.clone(),new_body(&new_on_sig,&spawn_with(Some(&runtime_spawn)))));
+        }
+
+        // HANDLE
+        if aaa.handle {
+            let handle_type = aaa.lib.handle_type();
+            live_mets.push((inter_take_handle.clone(),
+                quote!{
+                    #new_vis fn #inter_take_handle (&self) -> std::option::Option<#handle_type> {
+                        self.#handle.lock().unwrap_or_else(|error| error.into_inner()).take()
+                    }
+                }
+            ));
+        }
 
 
         // LIVE INTER METHODS AND TRAITS
@@ -373,10 +468,75 @@ pub fn generate_model( aa: AttributeArguments, item_impl: &ItemImpl , impl_vars:
     };
 
 
+    // ERROR 
+    let error_def = 
+    if aaa.has_error_type(){
+        Some(crate::model::impl_error(&mut cont, vars, &aaa, &new_vis))
+    } else { None };
+
+
+    // SHUTDOWN
+    if mac.eq(&model) && aaa.shutdown.active(){
+        aaa.shutdown.impl_shutdown(&mut cont, vars, &aaa.lib, &new_vis, mpsc);
+    }
+
+    // PRIORITY
+    if mac.eq(&model) && aaa.priority.active() {
+        aaa.priority.impl_priority(&mut cont, vars);
+    }
+
+    // WITH
+    if mac.eq(&model) && aaa.with {
+        crate::model::impl_with(&mut cont, vars, &aaa, &new_vis, oneshot, mpsc);
+    }
+
+    // EVENTS
+    if mac.eq(&model) && aaa.events.active() {
+        aaa.events.impl_events(&mut cont, vars, &aaa.lib, &new_vis);
+    }
+
+    // TRACE ( after every script field is declared )
+    if mac.eq(&model) && aaa.trace {
+        crate::model::impl_trace(&mut cont, vars, &new_vis, aaa.debut.active());
+    }
+
+    // VARIANT ( after every script field is declared )
+    if mac.eq(&model) && ( aaa.hooks.observe() || aaa.metrics ) {
+        crate::model::impl_variant(&mut cont, vars, &new_vis);
+    }
+
+    // METRICS
+    if mac.eq(&model) && aaa.metrics {
+        crate::model::impl_metrics(&mut cont, vars, &new_vis);
+    }
+
+    // POOL ( after every script field is declared )
+    if mac.eq(&model) && aaa.pool.active() {
+        aaa.pool.impl_pool(&mut cont, vars, &aaa.cast);
+    }
+
+    // SERDE ( after every script field is declared )
+    if mac.eq(&model) && aaa.serde.active() {
+        aaa.serde.impl_serde(&mut cont, vars, &aaa.lib, &new_vis);
+    }
+
+    // REGISTRY
+    if mac.eq(&model) && aaa.registry {
+        crate::model::impl_registry(&mut cont, vars, &new_vis);
+    }
+
+    // WEAK
+    if mac.eq(&model) && aaa.weak {
+        crate::model::impl_weak(&mut cont, vars, &aaa, &new_vis, &l_ty_generics, &l_where_clause);
+    }
+
+
     // SCRIPT DEFINITION
     let script_def = {
-        let Cont{ script_fields,..} = &mut cont;
+        let script_fields = cont.script_fields.iter().map(|(_,field)| field);
+        let script_attr = aaa.serde.script_attr();
         quote! {
+            #script_attr
             #new_vis enum #script_name #s_ty_generics #s_where_clause {
                 #(#script_fields),*
             }
@@ -402,9 +562,19 @@ pub fn generate_model( aa: AttributeArguments, item_impl: &ItemImpl , impl_vars:
     if mac.eq(&model) {
 
         let await_call  = async_decl.as_ref().map(|_| quote!{.await});
-        let end_of_play = error::end_of_life( &actor_name, &aaa.debut.clone() );  
+        let (end_decl, end_of_play) = aaa.on_end.play_end(vars, &aaa.debut);
         
         let debut_pat_type = if aaa.debut.active(){quote!{,#debut: std::time::SystemTime }} else { quote!{} };
+        let factory_pat_type = 
+        if aaa.supervise.active(){
+            let factory_type = aaa.supervise.factory_type(impl_vars);
+            quote!{,#inter_factory: #factory_type }
+        } else { quote!{} };
+        let high_pat_type = 
+        if aaa.priority.active(){ Some(aaa.priority.lane(vars,&aaa.lib).1) } else { None };
+        let pool_pat_type = aaa.pool.play_arg(vars).map(|(_,pat_type)| pat_type);
+        let registry_pat_type = if aaa.registry { Some(quote!{ ,#inter_registry: usize }) } else { None };
+        let metrics_pat_type = if aaa.metrics { Some(crate::model::metrics_play_arg(vars).1) } else { None };
 
         let MpscChannel{pat_type_receiver,..}      = mpsc;
         let Cont{script_mets,..} = &mut cont;
@@ -421,13 +591,78 @@ pub fn generate_model( aa: AttributeArguments, item_impl: &ItemImpl , impl_vars:
                 Lib::Tokio => quote!{std::option::Option::Some},
                 _ => quote!{std::result::Result::Ok}, 
             };
-            quote! {
-                #new_vis #async_decl fn #play ( #pat_type_receiver mut #actor: #actor_type #debut_pat_type ) {
-                    while let #ok_or_some (#msg) = #receiver.recv() #await_call {
-                        #msg.#direct ( &mut #actor ) #await_call;
+            let recv_msg = 
+            if aaa.priority.active() || aaa.tick.active() {
+                let mut recv_next = 
+                if aaa.tick.active(){ aaa.tick.play_recv(vars, &aaa.lib, &aaa.channel_impl, &aaa.tick.call(vars,item_impl)) } 
+                else { aaa.lib.recv_next(receiver) };
+                if aaa.priority.active(){ recv_next = aaa.priority.play_recv(vars, &recv_next); }
+                quote!{ std::option::Option::Some(#msg) = #recv_next }
+            }
+            else { quote!{ #ok_or_some (#msg) = #receiver.recv() #await_call } };
+            let tick_decl = if aaa.tick.active(){ Some(aaa.tick.play_decl(&aaa.lib)) } else { None };
+            let journal_decl = aaa.serde.play_decl(vars);
+            let drain = 
+            if aaa.priority.active(){ aaa.lib.play_drain(&[receiver, receiver_high]) } 
+            else { aaa.lib.play_drain(&[receiver]) };
+
+            let (play_decl, intercept, on_stop, join) = 
+            if aaa.shutdown.active(){
+                let (on_stop,join) = aaa.shutdown.play_end(vars,item_impl);
+                ( Some(aaa.shutdown.play_decl(vars)), Some(aaa.shutdown.play_intercept(vars)), on_stop, Some(join) )
+            } else { (None,None,None,None) };
+            let intercept = 
+            if aaa.trace { 
+                let trace_intercept = crate::model::trace_intercept(vars);
+                Some(quote!{ #trace_intercept #intercept })
+            } else { intercept };
+            let intercept = 
+            if aaa.metrics { 
+                let dequeue = crate::model::metrics_play_dequeue(vars);
+                Some(quote!{ #dequeue #intercept })
+            } else { intercept };
+
+            let (registry_guard, registry_drop) = 
+            if aaa.registry {
+                let (guard,drop) = crate::model::registry_guard(vars);
+                (Some(guard),Some(drop))
+            } else { (None,None) };
+            let (start, stop) = (aaa.hooks.play_start(vars,item_impl), aaa.hooks.play_stop(vars,item_impl));
+            let direct_call = aaa.hooks.play_direct(vars, item_impl, quote!{ #msg.#direct ( &mut #actor ) #await_call });
+            let direct_call = 
+            if aaa.metrics { crate::model::metrics_play_direct(vars, direct_call) } else { direct_call };
+            let direct_call = 
+            if aaa.trace { crate::model::trace_play_direct(vars, &aaa.lib, aaa.debut.active(), direct_call) } else { direct_call };
+            let direct_call = aaa.serde.play_direct(vars, direct_call);
+            let direct_call = aaa.pool.play_direct(vars, direct_call);
+
+            let play_loop = 
+            if aaa.supervise.active(){
+                aaa.supervise.play_loop(vars, &recv_msg, &intercept, &direct_call, &start)
+            } else {
+                quote!{
+                    while let #recv_msg {
+                        #intercept
+                        #direct_call;
                     }
+                }
+            };
+            quote! {
+                #new_vis #async_decl fn #play ( #pat_type_receiver mut #actor: #actor_type #debut_pat_type #factory_pat_type #high_pat_type #pool_pat_type #registry_pat_type #metrics_pat_type ) {
+                    #drain
+                    #end_decl
+                    #registry_guard
+                    #start
+                    #play_decl
+                    #tick_decl
+                    #journal_decl
+                    #play_loop
+                    #on_stop
+                    #stop
                     #legend_call
                     #end_of_play
+                    #registry_drop
+                    #join
                 }
             }
         };
@@ -466,6 +701,33 @@ pub fn generate_model( aa: AttributeArguments, item_impl: &ItemImpl , impl_vars:
                 ( quote!{ pub #debut: std::sync::Arc<std::time::SystemTime>,},
                   quote!{ pub #name: String,} )
             } else { (quote!{}, quote!{})};   
+            let (handle_field, handle_def) = if aaa.handle {
+                let handle_type = aaa.lib.handle_field_type(task_name);
+                ( quote!{ pub #handle: std::sync::Arc<std::sync::Mutex<#handle_type>>,},
+                  aaa.lib.handle_def(task_name,&new_vis) )
+            } else { (quote!{}, None) };
+            let high_field = 
+            if aaa.priority.active(){ Some(aaa.priority.lane(vars,&aaa.lib).0) } else { None };
+            let pool_field = 
+            if aaa.pool.active(){ Some(aaa.pool.live_field(vars)) } else { None };
+            let events_field = 
+            if aaa.events.active(){ Some(aaa.events.live_field(vars,&aaa.lib)) } else { None };
+            let registry_field = 
+            if aaa.registry { Some(quote!{ pub #inter_registry: usize, }) } else { None };
+            let (metrics_field, metrics_def) = 
+            if aaa.metrics {
+                ( Some(crate::model::metrics_live_field(vars)),
+                  Some(crate::model::metrics_def(vars,&new_vis)) )
+            } else { (None,None) };
+            let end_def = aaa.on_end.end_def(vars,&new_vis);
+            let remote_def = 
+            if aaa.remote { Some(crate::model::remote_def(vars,&aaa,&new_vis)) } else { None };
+            let (alive_field, weak_def) = 
+            if aaa.weak {
+                let fields = quote!{ #name_field #handle_field #high_field #pool_field #events_field #registry_field #metrics_field };
+                ( crate::model::weak_live_field(vars,&aaa,mpsc),
+                  Some(crate::model::weak_def(vars,&aaa,mpsc,&fields,&new_vis,&l_ty_generics,&l_where_clause)) )
+            } else { (None,None) };
 
             quote!{
                 #[derive(Clone)]
@@ -473,8 +735,21 @@ pub fn generate_model( aa: AttributeArguments, item_impl: &ItemImpl , impl_vars:
                     #pat_type_sender
                     #debut_field
                     #name_field
+                    #handle_field
+                    #high_field
+                    #pool_field
+                    #events_field
+                    #alive_field
+                    #registry_field
+                    #metrics_field
                     #group_pat_type_fields
                 }
+                #weak_def
+                #handle_def
+                #metrics_def
+                #end_def
+                #remote_def
+                #error_def
             }
         } else { 
 
@@ -483,6 +758,7 @@ pub fn generate_model( aa: AttributeArguments, item_impl: &ItemImpl , impl_vars:
                 #new_vis struct #live_name #l_ty_generics #l_where_clause {
                     #pat_type_sender
                 }
+                #error_def
             }
         }
     };
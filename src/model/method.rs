@@ -1,9 +1,9 @@
 use crate::error::{self,met_new_found, OriginVars};
 use crate::model::{
     ActorAttributeArguments, OneshotChannel, MpscChannel,
-    name,Cont,Vars,Lib,ImplVars,InterVars};
+    name,Channel,Cont,Vars,Lib,ImplVars,InterVars};
 
-use syn::{Path,Visibility,Signature,Ident,FnArg,Type,ReturnType,ImplItem,ItemImpl,Receiver,Token};
+use syn::{Path,Visibility,Signature,Ident,FnArg,Type,ReturnType,ImplItem,ItemImpl,Receiver,Token,TypeParamBound,punctuated::Punctuated};
 use proc_macro_error::abort;
 use proc_macro2::{TokenStream,Span};
 use quote::{quote,format_ident};
@@ -474,6 +474,14 @@ pub fn arguments_pat_type( args: &Vec<FnArg> ) -> (TokenStream, TokenStream) {
     ( args_ident, args_type )
 }
 
+pub fn to_fallible( sig: &mut Signature, error_name: &Ident ) {
+    let ty: Type = match &sig.output {
+        ReturnType::Default    => syn::parse_quote!{ () },
+        ReturnType::Type(_,ty) => *ty.clone(),
+    };
+    sig.output = syn::parse_quote!{ -> std::result::Result<#ty, #error_name> };
+}
+
 pub fn to_async( lib: &Lib, sig: &mut Signature ) {
     match lib {
         Lib::Std => (),
@@ -481,6 +489,132 @@ pub fn to_async( lib: &Lib, sig: &mut Signature ) {
     }
 }
 
+// the call of an Actor's method named by an option, 
+// looked up in the impl block as it may be private
+pub fn impl_method_call( item_impl: &ItemImpl, actor: &Ident, method: &Ident, args: TokenStream, help: &str ) -> TokenStream {
+
+    let sig = item_impl.items.iter().find_map(|item| 
+        match item {
+            ImplItem::Fn(met) if met.sig.ident.eq(method) => Some(met.sig.clone()),
+            _ => None,
+        });
+
+    match sig {
+        Some(sig) => {
+            let await_call = sig.asyncness.as_ref().map(|_| quote!{.await});
+            quote!{ #actor.#method ( #args ) #await_call; }
+        },
+        None => {
+            let msg = format!("No method named `{method}` in Actor's impl block.");
+            abort!(method,msg;help=help);
+        },
+    }
+}
+
+
+// IMPL TRAIT 
+
+// traits implemented by their own `Box<dyn Trait>`
+static BOXED_TRAITS: [&str;14] = [
+    "Fn", "FnMut", "FnOnce",
+    "Iterator", "DoubleEndedIterator", "ExactSizeIterator", "FusedIterator",
+    "Debug", "Display",
+    "Read", "Write", "BufRead", "Seek", "Hasher",
+];
+
+static AUTO_TRAITS: [&str;3] = ["Send", "Sync", "Unpin"];
+
+fn bound_ident(bound: &syn::TypeParamBound) -> Option<String> {
+    if let syn::TypeParamBound::Trait(trait_bound) = bound {
+        return trait_bound.path.segments.last().map(|s| s.ident.to_string());
+    }
+    None
+}
+
+// bounds of the `Box<dyn ..>` standing for an `impl Trait` type,
+// aborts pointing at `span_ty` if the type can not be sent to the actor
+pub fn impl_trait_bounds( org_err: &OriginVars, ty: &Type, span_ty: &Type ) -> Option<Punctuated<TypeParamBound,Token![+]>> {
+
+    let abort_ty = |reason: &str| -> ! {
+        let msg = org_err.origin(error::impl_trait_not_boxed(&quote!{#span_ty},reason));
+        abort!(span_ty,msg;help=error::IMPL_TRAIT_HELP);
+    };
+
+    match ty {
+        Type::ImplTrait(type_impl) => {
+            let mut bounds = type_impl.bounds.clone();
+            let traits = bounds.iter().filter_map(bound_ident)
+                .filter(|i| !AUTO_TRAITS.contains(&i.as_str()))
+                .collect::<Vec<_>>();
+            if traits.len() != 1 {
+                abort_ty("a trait object allows a single non-auto trait");
+            }
+            if !BOXED_TRAITS.contains(&traits[0].as_str()) {
+                abort_ty(&format!("`Box<dyn {0}>` does not implement `{0}`",traits[0]));
+            }
+            if to_string_wide(&bounds).contains(" impl "){
+                abort_ty("nested `impl Trait` types are not supported");
+            }
+            if !bounds.iter().filter_map(bound_ident).any(|i| i == "Send"){
+                bounds.push(syn::parse_quote!{ Send });
+            }
+            if !bounds.iter().any(|b| matches!(b, TypeParamBound::Lifetime(_))){
+                bounds.push(syn::parse_quote!{ 'static });
+            }
+            Some(bounds)
+        },
+        _ => {
+            if to_string_wide(ty).contains(" impl "){
+                abort_ty("nested `impl Trait` types are not supported");
+            }
+            None
+        },
+    }
+}
+
+// the reply type sent back by the actor and the boxed reply 
+// for an `impl Trait` return type
+pub fn box_impl_reply( org_err: &OriginVars, output: &Type, call: TokenStream ) -> (Type,TokenStream) {
+    let span_ty = match &org_err.sig.output {
+        ReturnType::Type(_,ty) => *ty.clone(),
+        ReturnType::Default    => output.clone(),
+    };
+    match impl_trait_bounds(org_err, output, &span_ty){
+        Some(bounds) => (syn::parse_quote!{ Box<dyn #bounds> }, quote!{ Box::new(#call) }),
+        None => (output.clone(), call),
+    }
+}
+
+// boxes the `impl Trait` arguments, returns the boxing statements
+// for the `live` method and the arguments of the `Script` field
+pub fn box_impl_args( org_err: &OriginVars, sig: &mut Signature, arguments: &[FnArg] ) -> (TokenStream, Vec<FnArg>) {
+
+    let typed = |a: &FnArg| if let FnArg::Typed(pat_ty) = a { Some(pat_ty.clone()) } else { None };
+    let span_tys = org_err.sig.inputs.iter().filter_map(typed).map(|p| p.ty);
+    let mut box_args  = quote!{};
+    let mut arguments = arguments.to_vec();
+
+    let sig_args = sig.inputs.iter_mut().filter_map(|a| if let FnArg::Typed(p) = a { Some(p) } else { None });
+    let args     = arguments.iter_mut().filter_map(|a| if let FnArg::Typed(p) = a { Some(p) } else { None });
+
+    for ((pat_ty,sig_pat_ty),span_ty) in args.zip(sig_args).zip(span_tys) {
+
+        if let Some(bounds) = impl_trait_bounds(org_err, &pat_ty.ty, &span_ty){
+            let syn::Pat::Ident(pat_ident) = &*pat_ty.pat else {
+                abort!(span_ty,org_err.origin(error::IMPL_TRAIT_PATTERN);help=error::IMPL_TRAIT_HELP);
+            };
+            let ident = &pat_ident.ident;
+            let boxed: Type = syn::parse_quote!{ Box<dyn #bounds> };
+            box_args.extend(quote!{ let #ident: #boxed = Box::new(#ident); });
+
+            // the `live` method requires what the `Script` field requires
+            sig_pat_ty.ty = syn::parse_quote!{ impl #bounds };
+            *pat_ty.ty = boxed;
+        }
+    }
+    (box_args, arguments)
+}
+
 
 // TOKENSTREAM FROM METHODS 
 
@@ -516,16 +650,22 @@ pub fn to_raw_parts (
     }: &mut Cont,
     aaa : &ActorAttributeArguments,
     oneshot: &OneshotChannel,
-    MpscChannel{
-        sender_call,..
-    }: &MpscChannel,
+    mpsc: &MpscChannel,
 ){  
-    let ActorAttributeArguments{ lib,interact,.. } = &aaa;
-    let Vars {actor,cust_name,script_name,impl_vars,inter_send,msg,..} = &vars;
+    let ActorAttributeArguments{ lib,interact,fallible,try_send,.. } = &aaa;
+    let Vars {actor,cust_name,script_name,impl_vars,inter_send,inter_recv,msg,error_name,..} = &vars;
     let ImplVars{ actor_name,actor_methods,.. } = &impl_vars;
 
     let group_wrap_variant = impl_vars.get_group_script_wrapper();
     let live_meth_send_recv = oneshot.decl(None);
+    let high_lane = 
+    if aaa.priority.active() { Some(aaa.priority.high_lane(vars, aaa, mpsc)) } else { None };
+    let mut timed_mets = vec![];
+    // methods sent to the actor, methods returning a type 
+    // and the `cast_`, `_deferred` counterparts
+    let mut sent_mets   = vec![];
+    let mut output_mets = vec![];
+    let mut extra_cont  = Cont::new();
     
     for mut method in actor_methods.clone() {
         
@@ -535,7 +675,109 @@ pub fn to_raw_parts (
         let await_call = sig.asyncness.as_ref().map(|_|quote!{.await});
         to_async(lib, &mut sig);
 
-        let error_send = error::direct_send(&script_name,&script_field_name);
+        // the channel the method is sent through
+        let lane = match &high_lane {
+            Some(high) if aaa.priority.contains(&sig.ident) => high,
+            _ => mpsc,
+        };
+        let MpscChannel{ sender_call, sender_call_err, sender_send, sender_try_call, sender_sync_call, sender_sync_send,.. } = lane;
+        let cast_sender_call = if *fallible { sender_call_err } else { sender_call };
+        match &method {
+            ActorMethod::Io{ stat: true,..} |
+            ActorMethod::O { stat: true,..} => (),
+            _ => { sent_mets.push(sig.ident.clone()); },
+        }
+
+        // methods waiting for a reply may time out 
+        let timeout = match &method {
+            ActorMethod::Io{ stat: false,..} |
+            ActorMethod::O { stat: false,..} => {
+                timed_mets.push(sig.ident.clone());
+                aaa.timeout.get(&sig.ident)
+            },
+            _ => None,
+        };
+        let fallible_met = *fallible || timeout.is_some();
+        let recv_output_err = 
+        if let Some(dur) = &timeout { oneshot.traced(aaa.timeout.recv_call(lib, inter_recv, error_name, dur)) }
+        else { oneshot.recv_call_err(error_name) };
+
+        let error_send = 
+        if fallible_met { quote!{} } 
+        else { error::direct_send(&script_name,&script_field_name) };
+        let direct_send = if fallible_met { quote!{ let _ = } } else { quote!{} };
+
+        let sender_call = if fallible_met { sender_call_err } else { sender_call };
+        let ok_ret = |ret: Option<TokenStream>| -> Option<TokenStream> { 
+            if *fallible { 
+                let ret = ret.unwrap_or(quote!{()});
+                Some(quote!{ std::result::Result::Ok(#ret) })
+            } else { ret }
+        };
+
+        // Live `try_` method, the message is sent without blocking, 
+        // only the methods with a reply are `async` to await it
+        let add_try_met = | live_mets: &mut Vec<(Ident,TokenStream)>, 
+                            vis: &Visibility, sig: &Signature, 
+                            pre: TokenStream, msg_variant: &TokenStream, post: TokenStream, reply: bool | {
+            if *try_send {
+                let mut sig = sig.clone();
+                sig.ident = crate::model::name::try_method(&sig.ident);
+                if !reply { sig.asyncness = None; }
+                if actor_methods.iter().any(|m| m.get_sig_and_field_name().0.ident.eq(&sig.ident)){
+                    let msg = error::var_name_conflict(&sig.ident,"method");
+                    abort!(Span::call_site(),msg;note=error::TRY_SEND_NAME_NOTE);
+                }
+                to_fallible(&mut sig, error_name);
+                live_mets.push((sig.ident.clone(),
+                quote!{
+                    #vis #sig {
+                        #pre
+                        let #msg = #msg_variant ;
+                        #sender_try_call
+                        #post
+                    }
+                }));
+            }
+        };
+
+        // Live `_deferred` method, the message is sent at call time 
+        // and the returned future awaits the reply only, with a bounded 
+        // channel the method is `async` and awaits room for the message
+        let bounded = !Channel::Unbounded.eq(&aaa.channel);
+        let add_deferred_met = | cont: &mut Cont,
+                                 vis: &Visibility, sig: &Signature, ident: &Ident, 
+                                 pre: TokenStream, msg_variant: &TokenStream, output: &Type | {
+            let mut sig = sig.clone();
+            sig.ident      = crate::model::name::deferred_method(ident);
+            if !bounded { sig.asyncness  = None; }
+            if actor_methods.iter().any(|m| m.get_sig_and_field_name().0.ident.eq(&sig.ident)){
+                let msg = error::var_name_conflict(&sig.ident,"method");
+                abort!(Span::call_site(),msg;note=error::DEFERRED_NAME_NOTE);
+            }
+            let (send_send, send_call) = 
+            if bounded { (sender_send, sender_call) } else { (sender_sync_send, sender_sync_call) };
+            let (output,send,recv) = 
+            if fallible_met {
+                ( quote!{ std::result::Result<#output, #error_name> },
+                  quote!{ let inter_sent = #send_send .map_err(|_error| #error_name :: SendClosed); },
+                  quote!{ inter_sent?; #recv_output_err })
+            } else {
+                ( quote!{ #output }, 
+                  quote!{ #send_call }, 
+                  oneshot.recv_call(cust_name,ident))
+            };
+            sig.output = syn::parse_quote!{ -> impl std::future::Future<Output = #output> + Send + 'static };
+            cont.live_mets.push((sig.ident.clone(),
+            quote!{
+                #vis #sig {
+                    #pre
+                    let #msg = #msg_variant ;
+                    #send
+                    async move { #recv }
+                }
+            }));
+        };
 
         // Debug arm
         let add_arm = | debug_arms: &mut Vec<TokenStream>,ident: &Ident | {
@@ -543,11 +785,64 @@ pub fn to_raw_parts (
             let str_field_name = format!("{}::{}",script_name.to_string() ,ident.to_string());
 
             let debug_arm = quote! {
-                #script_name :: #script_field_name {..} => write!(f, #str_field_name),
+                #script_name :: #ident {..} => write!(f, #str_field_name),
             };
             debug_arms.push(debug_arm);
         };
 
+        // Live `cast_` method, the message is sent without a reply channel
+        // and the result is dropped on the actor side
+        let add_cast_met = | cont: &mut Cont,
+                             vis: &Visibility, sig: &Signature, ident: &Ident, 
+                             pre: TokenStream, args_ident: Option<&TokenStream>, args_type: Option<&TokenStream> | {
+            let mut sig = sig.clone();
+            sig.ident   = crate::model::name::cast_method(ident);
+            sig.output  = ReturnType::Default;
+            if actor_methods.iter().any(|m| m.get_sig_and_field_name().0.ident.eq(&sig.ident)){
+                let msg = error::var_name_conflict(&sig.ident,"method");
+                abort!(Span::call_site(),msg;note=error::CAST_NAME_NOTE);
+            }
+            let cast_field_name = crate::model::name::script_field(&sig.ident);
+
+            // Debug Arm
+            let str_field_name = format!("{script_name}::{cast_field_name}");
+            cont.debug_arms.push(quote! {
+                #script_name :: #cast_field_name {..} => write!(f, #str_field_name),
+            });
+
+            // Direct Arm
+            let (arm_match,call_args,script_field) = 
+            if let (Some(args_ident),Some(args_type)) = (args_ident,args_type) {
+                ( quote!{ #cast_field_name { input: #args_ident } }, 
+                  quote!{ #args_ident },
+                  quote!{ #cast_field_name { input: #args_type } })
+            } else {
+                ( quote!{ #cast_field_name {} }, 
+                  quote!{ () },
+                  quote!{ #cast_field_name {} })
+            };
+            cont.direct_arms.push(quote!{
+                #script_name :: #arm_match => { let _ = #actor.#ident #call_args #await_call; },
+            });
+
+            // Live Method
+            let msg_variant = (*group_wrap_variant)(quote!{ #script_name :: #arm_match });
+            if *fallible { to_fallible(&mut sig, error_name); }
+            let ok_unit = ok_ret(None);
+            cont.live_mets.push((sig.ident.clone(),
+            quote!{
+                #vis #sig {
+                    #pre
+                    let #msg = #msg_variant ;
+                    #cast_sender_call
+                    #ok_unit
+                }
+            }));
+
+            // Script Field Struct
+            cont.script_fields.push((cast_field_name,script_field));
+        };
+
         let some_inter_vars = 
             |interact: bool,org_err:&OriginVars, sig: &Signature, arguments: &Vec<FnArg>, one: Option<&OneshotChannel>| -> Option<InterVars>
             {
@@ -576,7 +871,10 @@ pub fn to_raw_parts (
 
             ActorMethod::Io   { vis, org_err,  ident, stat,  arguments, output,.. } => {
                 check_met_name(ident,org_err);
-                let mut inter_vars = some_inter_vars(*interact, org_err, &sig, arguments,None);
+                let (box_args,arguments) = 
+                if *stat { (quote!{}, arguments.clone()) } 
+                else { box_impl_args(org_err, &mut sig, arguments) };
+                let mut inter_vars = some_inter_vars(*interact, org_err, &sig, &arguments,None);
                 let (args_ident,args_type) = arguments_pat_type(&arguments);
 
                 if *stat {
@@ -592,21 +890,38 @@ pub fn to_raw_parts (
                         #script_field_name { input: #args_ident,  #inter_send }
                     };
 
+                    let (send_output,reply) = box_impl_reply(org_err, output, quote!{ #actor.#ident #args_ident #await_call });
                     let direct_arm = {
                         quote! {
-                            #script_name :: #arm_match => {#inter_send .send( #actor.#ident #args_ident #await_call ) #error_send ;}
+                            #script_name :: #arm_match => {#direct_send #inter_send .send( #reply ) #error_send ;}
                         }
                     };
                     direct_arms.push(direct_arm);
                     
                     // Live Method
-                    let recv_output = oneshot.recv_call(cust_name,&ident);
+                    let recv_output = 
+                    if fallible_met { recv_output_err.clone() } 
+                    else { oneshot.recv_call(cust_name,&ident) };
                     let msg_variant = (*group_wrap_variant)(quote!{ #script_name :: #arm_match });
                     
-                    let (inter_gets, sig) = 
+                    let (inter_gets, mut sig) = 
                     if let Some(inter_vars) = &mut inter_vars{
                         ( Some( inter_vars.get_getters_decl()), inter_vars.new_sig.clone() )
                     } else {( None,sig)};
+                    let inter_gets = quote!{ #inter_gets #box_args };
+                    add_try_met(live_mets, vis, &sig, 
+                        quote!{ #live_meth_send_recv #inter_gets }, 
+                        &msg_variant, recv_output_err.clone(), true);
+                    output_mets.push(ident.clone());
+                    if aaa.cast.contains(ident){
+                        add_cast_met(&mut extra_cont, vis, &sig, ident,
+                            quote!{ #inter_gets }, Some(&args_ident), Some(&args_type));
+                    }
+                    if aaa.deferred.contains(ident){
+                        add_deferred_met(&mut extra_cont, vis, &sig, ident,
+                            quote!{ #live_meth_send_recv #inter_gets }, &msg_variant, output);
+                    }
+                    if fallible_met { to_fallible(&mut sig, error_name); }
 
                     let live_met    = quote! {
 
@@ -623,7 +938,7 @@ pub fn to_raw_parts (
                     live_mets.push((ident.clone(),live_met));
 
                     // Script Field Struct
-                    let send_pat_type = oneshot.pat_type_send(&*output);
+                    let send_pat_type = oneshot.pat_type_send(&send_output);
                     let script_field = quote! {
                         #script_field_name {
                             input: #args_type,
@@ -631,7 +946,7 @@ pub fn to_raw_parts (
                         }
                     };
 
-                    script_fields.push(script_field);
+                    script_fields.push((script_field_name.clone(),script_field));
                 }
             },
 
@@ -640,7 +955,8 @@ pub fn to_raw_parts (
             ActorMethod::I    { vis,org_err, ident, arguments ,..} => {
                 
                 check_met_name(ident,org_err);
-                let mut inter_vars = some_inter_vars(*interact, org_err, &sig, arguments, Some(oneshot));
+                let (box_args,arguments) = box_impl_args(org_err, &mut sig, arguments);
+                let mut inter_vars = some_inter_vars(*interact, org_err, &sig, &arguments, Some(oneshot));
 
                 let (args_ident,args_type) = arguments_pat_type(&arguments);
     
@@ -662,15 +978,22 @@ pub fn to_raw_parts (
                 let msg_variant = (*group_wrap_variant)(quote!{ #script_name :: #arm_match });
                 
                 // get getters decl and change sig
-                let (inter_gets, sig, ret_chan_end) = 
+                let (inter_gets, mut sig, ret_chan_end) = 
                 if let Some(inter_vars) = &mut inter_vars{
                     (
                         Some(inter_vars.get_getters_decl()),
                         inter_vars.new_sig.clone(),
-                        inter_vars.some_ret_name(),
+                        inter_vars.some_ret_name().map(|x| quote!{#x}),
                     )
 
                 } else {( None,sig,None)};
+                let inter_gets = quote!{ #inter_gets #box_args };
+                let try_ret = ret_chan_end.clone().unwrap_or(quote!{()});
+                add_try_met(live_mets, vis, &sig, 
+                    quote!{ #inter_gets }, 
+                    &msg_variant, quote!{ std::result::Result::Ok(#try_ret) }, false);
+                if *fallible { to_fallible(&mut sig, error_name); }
+                let ret_chan_end = ok_ret(ret_chan_end);
 
                 let live_met = quote!{
     
@@ -690,7 +1013,7 @@ pub fn to_raw_parts (
                         input: #args_type,
                     }
                 };
-                script_fields.push(script_field);
+                script_fields.push((script_field_name.clone(),script_field));
                 
             },
             ActorMethod::O    { vis, ident, org_err, stat, output ,..} => {
@@ -711,16 +1034,33 @@ pub fn to_raw_parts (
                         #script_field_name{ inter_send }
                     };
         
+                    let (send_output,reply) = box_impl_reply(org_err, output, quote!{ #actor.#ident #args_ident #await_call });
                     let direct_arm = quote!{
-                        #script_name::#arm_match => {inter_send.send(#actor.#ident #args_ident #await_call) #error_send ;}
+                        #script_name::#arm_match => {#direct_send inter_send.send(#reply) #error_send ;}
                     };
                     direct_arms.push(direct_arm);
 
 
 
                     // Live Method
-                    let recv_output = oneshot.recv_call(cust_name,&ident);
+                    let recv_output = 
+                    if fallible_met { recv_output_err.clone() } 
+                    else { oneshot.recv_call(cust_name,&ident) };
+                    let mut sig = sig;
                     let msg_variant = (*group_wrap_variant)(quote!{ #script_name :: #arm_match });
+                    add_try_met(live_mets, vis, &sig, 
+                        live_meth_send_recv.clone(), 
+                        &msg_variant, recv_output_err.clone(), true);
+                    output_mets.push(ident.clone());
+                    if aaa.cast.contains(ident){
+                        add_cast_met(&mut extra_cont, vis, &sig, ident,
+                            quote!{}, None, None);
+                    }
+                    if aaa.deferred.contains(ident){
+                        add_deferred_met(&mut extra_cont, vis, &sig, ident,
+                            live_meth_send_recv.clone(), &msg_variant, output);
+                    }
+                    if fallible_met { to_fallible(&mut sig, error_name); }
                     let live_met = quote!{
                     
                         #vis #sig {
@@ -733,7 +1073,7 @@ pub fn to_raw_parts (
                     live_mets.push((ident.clone(), live_met));
                 
                     // Script Field Struct
-                    let send_pat_type = oneshot.pat_type_send(&*output);
+                    let send_pat_type = oneshot.pat_type_send(&send_output);
 
 
                     let script_field = quote!{
@@ -741,7 +1081,7 @@ pub fn to_raw_parts (
                             #send_pat_type,
                         }
                     };
-                    script_fields.push(script_field);
+                    script_fields.push((script_field_name.clone(),script_field));
                 }
             },
 
@@ -765,11 +1105,18 @@ pub fn to_raw_parts (
 
                 // Live Method
                 let msg_variant = (*group_wrap_variant)(quote!{ #script_name :: #arm_match });
+                let mut sig = sig;
+                add_try_met(live_mets, vis, &sig, 
+                    quote!{}, 
+                    &msg_variant, quote!{ std::result::Result::Ok(()) }, false);
+                if *fallible { to_fallible(&mut sig, error_name); }
+                let ok_unit = ok_ret(None);
                 let live_met = quote!{
                 
                     #vis #sig {
                         let #msg = #msg_variant ;
                         #sender_call
+                        #ok_unit
                     }
                 };
                 live_mets.push((ident.clone(),live_met));
@@ -779,10 +1126,18 @@ pub fn to_raw_parts (
                     
                     #script_field_name {}
                 };
-                script_fields.push(script_field);
+                script_fields.push((script_field_name.clone(),script_field));
             },
         }
     } 
+    aaa.timeout.check_methods(&timed_mets);
+    aaa.cast.check_methods(&output_mets,error::AVAIL_CAST);
+    aaa.deferred.check_methods(&output_mets,error::AVAIL_DEFERRED);
+    aaa.priority.check_methods(&sent_mets);
+    live_mets.extend(extra_cont.live_mets);
+    debug_arms.extend(extra_cont.debug_arms);
+    direct_arms.extend(extra_cont.direct_arms);
+    script_fields.extend(extra_cont.script_fields);
 }
 
 
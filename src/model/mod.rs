@@ -41,8 +41,10 @@ pub fn get_channels_one_mpsc(
         group_script_type
     } else { script_type };
 
+    let mut oneshot = OneshotChannel::new(inter_send,inter_recv,&aaa.lib);
+    if aaa.trace { oneshot.trace = Some(vars.cust_name.clone()); }
     (
-        OneshotChannel::new(inter_send,inter_recv,&aaa.lib),
+        oneshot,
         MpscChannel::new(vars,aaa,script_type)   
     )
 }
@@ -54,7 +56,7 @@ pub struct Cont {
     live_mets    : Vec<(Ident,TokenStream)>,
     live_trts    : Vec<(Ident,TokenStream)>,
 
-    script_fields: Vec<TokenStream>,
+    script_fields: Vec<(Ident,TokenStream)>,
     direct_arms  : Vec<TokenStream>,
     debug_arms   : Vec<TokenStream>,
 
@@ -97,6 +99,41 @@ pub struct Vars {
     pub inter_get_count:   Ident,
     pub inter_set_name:    Ident,
     pub inter_get_name:    Ident,
+    pub inter_factory:     Ident,
+    pub inter_restarts:    Ident,
+    pub inter_shutdown:    Ident,
+    pub inter_join:        Ident,
+    pub inter_joins:       Ident,
+    pub handle:            Ident,
+    pub inter_take_handle: Ident,
+    pub with:              Ident,
+    pub with_mut:          Ident,
+    pub inter_fn:          Ident,
+    pub sender_high:       Ident,
+    pub receiver_high:     Ident,
+    pub inter_wake:        Ident,
+    pub inter_variant:     Ident,
+    pub inter_pool:        Ident,
+    pub inter_pool_index:  Ident,
+    pub inter_key:         Ident,
+    pub inter_queued:      Ident,
+    pub inter_events:      Ident,
+    pub inter_get_emit:    Ident,
+    pub subscribe:         Ident,
+    pub inter_alive:       Ident,
+    pub downgrade:         Ident,
+    pub upgrade:           Ident,
+    pub inter_registry:    Ident,
+    pub inter_get_registry:Ident,
+    pub register:          Ident,
+    pub lookup:            Ident,
+    pub registered:        Ident,
+    pub deregister:        Ident,
+    pub inter_metrics:     Ident,
+    pub metrics:           Ident,
+    pub inter_trace:       Ident,
+    pub inter_span:        Ident,
+    pub inter_parent:      Ident,
     pub intername:         Ident,
     pub msg:               Ident,
     pub self_:             Ident,
@@ -105,6 +142,22 @@ pub struct Vars {
     pub cust_name:         Ident,
     pub script_name:       Ident,
     pub live_name:         Ident,
+    pub error_name:        Ident,
+    pub weak_name:         Ident,
+    pub metrics_name:      Ident,
+    pub end_name:          Ident,
+    pub remote_name:       Ident,
+    pub server_name:       Ident,
+    pub reply_name:        Ident,
+    pub task_name:         Ident,
+    pub replay:            Ident,
+    pub inter_journal:     Ident,
+    pub inter_replay_sender:Ident,
+    pub inter_replay_keep: Ident,
+    pub inter_read_frame:  Ident,
+    pub inter_write_frame: Ident,
+    pub inter_runtime:     Ident,
+    pub inter_error:       Ident,
     pub script_type:        Type,
 }
 
@@ -118,6 +171,7 @@ impl Vars {
         let script_type: Type;
         let script_name;
         let live_name;
+        let error_name;
         let actor;
 
         match (mac,model){
@@ -125,18 +179,28 @@ impl Vars {
                 actor = format_ident!("actor");
                 script_name = name::script(&cust_name);
                 live_name   = name::live(&cust_name);
+                error_name  = name::error(&cust_name);
             },
             (Model::Group,Model::Group) => { 
                 actor = format_ident!("group");
                 script_name = name::group_script(&cust_name);
                 live_name   = name::group_live(&cust_name);
+                error_name  = name::group_error(&cust_name);
             },
                                       _ => { 
                 actor = format_ident!("actor");
                 script_name = name::script_group(&cust_name);
                 live_name   = name::live_group(&cust_name);
+                error_name  = name::error_group(&cust_name);
             },
         }
+        let weak_name = name::weak_live(&cust_name);
+        let metrics_name = name::metrics(&cust_name);
+        let end_name = name::end(&cust_name);
+        let remote_name = name::remote(&cust_name);
+        let server_name = name::server(&cust_name);
+        let reply_name = name::reply(&cust_name);
+        let task_name = name::task(&cust_name);
         let(_,ty_generics,_) = model_generics.split_for_impl();
         script_type = syn::parse_quote!{ #script_name #ty_generics };
 
@@ -162,6 +226,49 @@ impl Vars {
             inter_get_count:  format_ident!("inter_get_count"),
             inter_set_name:   format_ident!("inter_set_name"),
             inter_get_name:   format_ident!("inter_get_name"),
+            inter_factory:    format_ident!("inter_factory"),
+            inter_restarts:   format_ident!("inter_restarts"),
+            inter_shutdown:   format_ident!("inter_shutdown"),
+            inter_join:       format_ident!("inter_join"),
+            inter_joins:      format_ident!("inter_joins"),
+            handle:           format_ident!("handle"),
+            inter_take_handle:format_ident!("inter_take_handle"),
+            with:             format_ident!("with"),
+            with_mut:         format_ident!("with_mut"),
+            inter_fn:         format_ident!("inter_fn"),
+            sender_high:      format_ident!("sender_high"),
+            receiver_high:    format_ident!("receiver_high"),
+            inter_wake:       format_ident!("inter_wake"),
+            inter_variant:    format_ident!("inter_variant"),
+            inter_pool:       format_ident!("inter_pool"),
+            inter_pool_index: format_ident!("inter_pool_index"),
+            inter_key:        format_ident!("inter_key"),
+            inter_queued:     format_ident!("inter_queued"),
+            inter_events:     format_ident!("inter_events"),
+            inter_get_emit:   format_ident!("inter_get_emit"),
+            subscribe:        format_ident!("subscribe"),
+            inter_alive:      format_ident!("inter_alive"),
+            downgrade:        format_ident!("downgrade"),
+            upgrade:          format_ident!("upgrade"),
+            inter_registry:   format_ident!("inter_registry"),
+            inter_get_registry:format_ident!("inter_get_registry"),
+            register:         format_ident!("register"),
+            lookup:           format_ident!("lookup"),
+            registered:       format_ident!("registered"),
+            deregister:       format_ident!("deregister"),
+            inter_metrics:    format_ident!("inter_metrics"),
+            metrics:          format_ident!("metrics"),
+            inter_trace:      format_ident!("inter_trace"),
+            inter_span:       format_ident!("inter_span"),
+            inter_parent:     format_ident!("inter_parent"),
+            replay:           format_ident!("replay"),
+            inter_journal:    format_ident!("inter_journal"),
+            inter_replay_sender:format_ident!("inter_replay_sender"),
+            inter_replay_keep:format_ident!("inter_replay_keep"),
+            inter_read_frame: format_ident!("inter_read_frame"),
+            inter_write_frame:format_ident!("inter_write_frame"),
+            inter_runtime:    format_ident!("inter_runtime"),
+            inter_error:      format_ident!("InterError"),
             intername:        format_ident!("InterName"),
             msg:              format_ident!("msg"),
             self_:            format_ident!("self"),
@@ -170,6 +277,14 @@ impl Vars {
             cust_name,
             script_name,
             live_name,
+            error_name,
+            weak_name,
+            metrics_name,
+            end_name,
+            remote_name,
+            server_name,
+            reply_name,
+            task_name,
             script_type,
         }
     }
@@ -177,6 +292,47 @@ impl Vars {
     pub fn get_inter_live_methods(&self,aaa: &ActorAttributeArguments) 
         -> Vec<&Ident> {
 
+        let mut mets = self.get_inter_debut_methods(aaa);
+        if aaa.shutdown.active(){
+            let Vars{ inter_shutdown, inter_join,.. } = &self;
+            mets.push(inter_shutdown);
+            mets.push(inter_join);
+        }
+        if aaa.handle {
+            let Vars{ inter_take_handle,.. } = &self;
+            mets.push(inter_take_handle);
+        }
+        if aaa.with {
+            let Vars{ with, with_mut,.. } = &self;
+            mets.push(with);
+            mets.push(with_mut);
+        }
+        if aaa.events.active() {
+            let Vars{ subscribe, inter_get_emit,.. } = &self;
+            mets.push(subscribe);
+            mets.push(inter_get_emit);
+        }
+        if aaa.weak {
+            let Vars{ downgrade,.. } = &self;
+            mets.push(downgrade);
+        }
+        if aaa.registry {
+            let Vars{ register, lookup, registered, deregister,.. } = &self;
+            mets.push(register);
+            mets.push(lookup);
+            mets.push(registered);
+            mets.push(deregister);
+        }
+        if aaa.metrics {
+            let Vars{ metrics,.. } = &self;
+            mets.push(metrics);
+        }
+        mets
+    }
+
+    fn get_inter_debut_methods(&self,aaa: &ActorAttributeArguments) 
+        -> Vec<&Ident> {
+
         if aaa.debut.active(){
             let Vars{         
                 inter_get_debut,
@@ -32,6 +32,46 @@ pub fn live(name: &Ident) -> Ident{
     format_ident!("{}",new_name)
 }
 
+pub fn error(name: &Ident) -> Ident{
+    let new_name = name.to_string() + "Error";
+    format_ident!("{}",new_name)
+}
+
+pub fn metrics(name: &Ident) -> Ident{
+    let new_name = name.to_string() + "Metrics";
+    format_ident!("{}",new_name)
+}
+
+pub fn end(name: &Ident) -> Ident{
+    let new_name = name.to_string() + "End";
+    format_ident!("{}",new_name)
+}
+
+pub fn remote(name: &Ident) -> Ident{
+    let new_name = name.to_string() + "Remote";
+    format_ident!("{}",new_name)
+}
+
+pub fn server(name: &Ident) -> Ident{
+    let new_name = name.to_string() + "Server";
+    format_ident!("{}",new_name)
+}
+
+pub fn reply(name: &Ident) -> Ident{
+    let new_name = name.to_string() + "Reply";
+    format_ident!("{}",new_name)
+}
+
+pub fn task(name: &Ident) -> Ident{
+    let new_name = name.to_string() + "Task";
+    format_ident!("{}",new_name)
+}
+
+pub fn weak_live(name: &Ident) -> Ident{
+    let new_name = name.to_string() + "WeakLive";
+    format_ident!("{}",new_name)
+}
+
 pub fn script_field(name: &Ident) -> Ident{
     let new_name = fn_to_struct(&name.to_string());
     format_ident!("{}",new_name)
@@ -48,6 +88,11 @@ pub fn group_live(name: &Ident) -> Ident{
     format_ident!("{}",new_name)
 }
 
+pub fn group_error(name: &Ident) -> Ident{
+    let new_name = name.to_string() + "GroupError";
+    format_ident!("{}",new_name)
+}
+
 // GroupActor
 pub fn script_group(name: &Ident) -> Ident{
     let new_name = name.to_string() + "ScriptGroup";
@@ -59,6 +104,24 @@ pub fn live_group(name: &Ident) -> Ident{
     format_ident!("{}",new_name)
 }
 
+pub fn error_group(name: &Ident) -> Ident{
+    let new_name = name.to_string() + "ErrorGroup";
+    format_ident!("{}",new_name)
+}
+
+// Live `try_` methods
+pub fn try_method(name: &Ident) -> Ident{
+    format_ident!("try_{name}")
+}
+
+pub fn cast_method(name: &Ident) -> Ident{
+    format_ident!("cast_{name}")
+}
+
+pub fn deferred_method(name: &Ident) -> Ident{
+    format_ident!("{name}_deferred")
+}
+
 pub fn check_name_conflict( names: Vec<&Ident> ){
 
     let mut names = 
@@ -0,0 +1,88 @@
+use interthread::actor as life;
+
+// STD
+#[test]
+fn actor_sync_cast() {
+    pub struct Actor(u32);
+    #[life(cast)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn add(&mut self, v:u32)->u32{ self.0 += v; self.0 }
+        pub fn incr(&mut self)->u32{ self.0 += 1; self.0 }
+        pub fn output(&self)->u32{ self.0 }
+    }
+    let mut live = ActorLive::new();
+    live.cast_add(5);
+    live.cast_incr();
+    assert_eq!( live.add(1), 7);
+    assert_eq!( live.output(), 7);
+}
+
+#[test]
+fn actor_sync_cast_methods() {
+    pub struct Actor(Vec<String>);
+    #[life(cast(log), fallible)]
+    impl Actor {
+        pub fn new() -> Self{ Self(vec![]) }
+        pub fn log(&mut self, line: String)->usize{ self.0.push(line); self.0.len() }
+        pub fn lines(&self)->Vec<String>{ self.0.clone() }
+    }
+    let mut live = ActorLive::new();
+    assert_eq!( live.cast_log("a".to_string()), Ok(()));
+    assert_eq!( live.log("b".to_string()), Ok(2));
+    assert_eq!( live.lines(), Ok(vec!["a".to_string(),"b".to_string()]));
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_cast() {
+    pub struct Actor(u32);
+    #[life(lib = "tokio", cast(add))]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub async fn add(&mut self, v:u32)->u32{ self.0 += v; self.0 }
+        pub fn output(&self)->u32{ self.0 }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let mut live = ActorLive::new();
+        live.cast_add(2).await;
+        live.cast_add(3).await;
+        assert_eq!( live.output().await, 5);
+    });
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_cast() {
+    pub struct Actor(u32);
+    #[life(lib = "async_std", cast, channel = 2)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn add(&mut self, v:u32)->u32{ self.0 += v; self.0 }
+    }
+    async_std::task::block_on(async {
+        let mut live = ActorLive::new();
+        live.cast_add(2).await;
+        assert_eq!( live.add(3).await, 5);
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_cast() {
+    pub struct Actor(u32);
+    #[life(lib = "smol", cast(incr))]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn incr(&mut self)->u32{ self.0 += 1; self.0 }
+        pub fn output(&self)->u32{ self.0 }
+    }
+    smol::block_on(async {
+        let mut live = ActorLive::new();
+        live.cast_incr().await;
+        live.cast_incr().await;
+        assert_eq!( live.output().await, 2);
+    });
+}
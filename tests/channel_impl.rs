@@ -0,0 +1,76 @@
+use interthread::actor as life;
+use std::sync::{Arc,Barrier};
+use std::time::Duration;
+
+// CROSSBEAM
+#[test]
+fn actor_sync_crossbeam() {
+    pub struct Actor(i8);
+    #[life(channel_impl = "crossbeam", handle)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn input(&mut self, v:i8){ self.0 = v }
+        pub fn output(&self)->i8{ self.0 }
+    }
+    let mut live = ActorLive::new();
+    let handle = live.inter_take_handle().unwrap();
+    live.input(3);
+    assert_eq!( live.output(), 3);
+    // the sender is a crossbeam sender
+    let sender: &crossbeam_channel::Sender<ActorScript> = &live.sender;
+    assert!( sender.is_empty());
+    drop(live);
+    assert!( handle.join().is_ok());
+}
+
+#[test]
+fn actor_sync_crossbeam_try_send() {
+    pub struct Actor(i8, Arc<Barrier>);
+    #[life(channel_impl = "crossbeam", channel = 1, try_send)]
+    impl Actor {
+        pub fn new(barrier: Arc<Barrier>) -> Self{ Self(0,barrier) }
+        pub fn input(&mut self, v:i8){ self.0 = v }
+        pub fn output(&self)->i8{ self.0 }
+        pub fn pause(&self){ self.1.wait(); self.1.wait(); }
+    }
+    let barrier = Arc::new(Barrier::new(2));
+    let mut live = ActorLive::new(barrier.clone());
+    live.pause();
+    barrier.wait();
+    assert_eq!( live.try_input(5), Ok(()));
+    assert_eq!( live.try_input(6), Err(ActorError::Full));
+    barrier.wait();
+    assert_eq!( live.output(), 5);
+}
+
+// FLUME
+#[test]
+fn actor_sync_flume() {
+    pub struct Actor(u32);
+    #[life(channel_impl = "flume", channel = 4, tick(every = "5ms", method = on_tick))]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn ticks(&self)->u32{ self.0 }
+        fn on_tick(&mut self){ self.0 += 1 }
+    }
+    let live = ActorLive::new();
+    std::thread::sleep(Duration::from_millis(30));
+    assert!( live.ticks() >= 2);
+    assert_eq!( live.sender.len(), 0);
+}
+
+#[test]
+fn actor_sync_flume_pool() {
+    pub struct Actor;
+    #[life(channel_impl = "flume", pool(size = 2), weak)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn ping(&self)->bool{ true }
+    }
+    let live = ActorLive::new();
+    for _ in 0..4 { assert!( live.ping()); }
+    let weak = live.downgrade();
+    assert!( weak.upgrade().unwrap().ping());
+    drop(live);
+    assert!( weak.upgrade().is_none());
+}
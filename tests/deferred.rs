@@ -0,0 +1,123 @@
+use interthread::actor as life;
+
+// TOKIO
+#[test]
+fn actor_tokio_deferred() {
+    pub struct Actor(u32);
+    #[life(lib = "tokio", deferred)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub async fn add(&mut self, v:u32)->u32{ self.0 += v; self.0 }
+        pub fn output(&self)->u32{ self.0 }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let mut live = ActorLive::new();
+        // the messages are sent in call order
+        let first  = live.add_deferred(1);
+        let second = live.add_deferred(2);
+        let third  = live.add_deferred(3);
+        assert_eq!( third.await,  6);
+        assert_eq!( first.await,  1);
+        assert_eq!( second.await, 3);
+        assert_eq!( live.output_deferred().await, 6);
+        assert_eq!( live.add(1).await, 7);
+    });
+}
+
+#[test]
+fn actor_tokio_deferred_spawn() {
+    pub struct Actor;
+    #[life(lib = "tokio", deferred(double), fallible)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn double(&self, v:u32)->u32{ v * 2 }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let live = ActorLive::new();
+        let handles = (0..4).map(|v| tokio::spawn(live.double_deferred(v))).collect::<Vec<_>>();
+        let mut loc = vec![];
+        for handle in handles {
+            loc.push(handle.await.unwrap());
+        }
+        assert_eq!( loc, vec![Ok(0),Ok(2),Ok(4),Ok(6)]);
+    });
+}
+
+#[test]
+fn actor_tokio_deferred_bounded() {
+    pub struct Actor(u32);
+    #[life(lib = "tokio", channel = 1, deferred)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn add(&mut self, v:u32)->u32{ self.0 += v; self.0 }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let mut live = ActorLive::new();
+        // awaits room in the channel, then the reply
+        let first  = live.add_deferred(1).await;
+        let second = live.add_deferred(2).await;
+        let third  = live.add_deferred(3).await;
+        assert_eq!( third.await,  6);
+        assert_eq!( first.await,  1);
+        assert_eq!( second.await, 3);
+    });
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_deferred() {
+    pub struct Actor(u32);
+    #[life(lib = "async_std", deferred(add), timeout = "1s")]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn add(&mut self, v:u32)->u32{ self.0 += v; self.0 }
+    }
+    async_std::task::block_on(async {
+        let mut live = ActorLive::new();
+        let first  = live.add_deferred(1);
+        let second = live.add_deferred(2);
+        assert_eq!( second.await, Ok(3));
+        assert_eq!( first.await,  Ok(1));
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_deferred() {
+    pub struct Actor(u32);
+    #[life(lib = "smol", deferred)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn add(&mut self, v:u32)->u32{ self.0 += v; self.0 }
+    }
+    smol::block_on(async {
+        let mut live = ActorLive::new();
+        let first  = live.add_deferred(1);
+        let second = live.add_deferred(2);
+        assert_eq!( second.await, 3);
+        assert_eq!( first.await,  1);
+    });
+}
+
+#[test]
+fn actor_smol_deferred_bounded() {
+    pub struct Actor(u32);
+    #[life(lib = "smol", channel = 2, deferred, fallible)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn add(&mut self, v:u32)->u32{ self.0 += v; self.0 }
+    }
+    smol::block_on(async {
+        let mut live = ActorLive::new();
+        let first  = live.add_deferred(1).await;
+        let second = live.add_deferred(2).await;
+        assert_eq!( second.await, Ok(3));
+        assert_eq!( first.await,  Ok(1));
+    });
+}
@@ -0,0 +1,105 @@
+use interthread::actor as life;
+
+#[derive(Debug,Clone,PartialEq)]
+pub enum Event {
+    Changed(i8),
+    Reset,
+}
+
+// STD
+#[test]
+fn actor_sync_events() {
+    pub struct Actor(i8);
+    #[life(interact, events(Event))]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn input(&mut self, v:i8, inter_emit: impl Fn(Event)){ self.0 = v; inter_emit(Event::Changed(v)) }
+        pub fn reset(&mut self, inter_emit: impl Fn(Event))->i8{ self.0 = 0; inter_emit(Event::Reset); self.0 }
+        pub fn output(&self)->i8{ self.0 }
+    }
+    let mut live = ActorLive::new();
+    // no subscribers yet
+    live.input(1);
+    assert_eq!( live.output(), 1);
+    let first  = live.subscribe();
+    let second = live.clone().subscribe();
+    live.input(3);
+    assert_eq!( live.reset(), 0);
+
+    for receiver in [&first,&second] {
+        assert_eq!( receiver.recv(), Ok(Event::Changed(3)));
+        assert_eq!( receiver.recv(), Ok(Event::Reset));
+        assert!( receiver.try_recv().is_err());
+    }
+    // a dropped subscriber is removed
+    drop(second);
+    live.input(5);
+    assert_eq!( first.recv(), Ok(Event::Changed(5)));
+    assert_eq!( live.output(), 5);
+    drop(live);
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_events() {
+    pub struct Actor(i8);
+    #[life(lib = "tokio", interact, events(Event, capacity = 2))]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub async fn input(&mut self, v:i8, inter_emit: impl Fn(Event))->i8{ self.0 = v; inter_emit(Event::Changed(v)); v }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let mut live = ActorLive::new();
+        let mut receiver = live.subscribe();
+        assert_eq!( live.input(1).await, 1);
+        assert_eq!( receiver.recv().await, Ok(Event::Changed(1)));
+
+        // the capacity is exceeded, the oldest events are lost
+        for v in 2..5 { assert_eq!( live.input(v).await, v); }
+        assert!( receiver.recv().await.is_err());
+        assert_eq!( receiver.recv().await, Ok(Event::Changed(3)));
+        assert_eq!( receiver.recv().await, Ok(Event::Changed(4)));
+    });
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_events() {
+    pub struct Actor;
+    #[life(lib = "async_std", interact, events(Event))]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn reset(&mut self, inter_emit: impl Fn(Event)){ inter_emit(Event::Reset) }
+    }
+    async_std::task::block_on(async {
+        let mut live = ActorLive::new();
+        let mut receiver = live.subscribe();
+        live.reset().await;
+        assert_eq!( receiver.recv().await, Ok(Event::Reset));
+        drop(live);
+        // the channel is closed with the last live instance
+        assert!( receiver.recv().await.is_err());
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_events() {
+    pub struct Actor;
+    #[life(lib = "smol", interact, events(Vec<u8>, capacity = 1))]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn input(&mut self, v:u8, inter_emit: impl Fn(Vec<u8>))->u8{ inter_emit(vec![v]); v }
+    }
+    smol::block_on(async {
+        let mut live = ActorLive::new();
+        let mut receiver = live.subscribe();
+        assert_eq!( live.input(1).await, 1);
+        assert_eq!( live.input(2).await, 2);
+        // overflow, the oldest event is replaced
+        assert!( receiver.recv().await.is_err());
+        assert_eq!( receiver.recv().await, Ok(vec![2]));
+    });
+}
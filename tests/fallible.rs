@@ -0,0 +1,131 @@
+use interthread::{actor as life, group};
+
+// STD
+#[test]
+fn actor_sync_fallible() {
+    pub struct Actor(i8);
+    #[life(fallible)]
+    impl Actor {
+        pub fn new() -> Self{Self(0)}
+        pub fn input(&mut self, v:i8){self.0 = v}
+        pub fn output(&self)->i8{self.0}
+        pub fn in_out(&self,v:i8)->i8{v}
+        pub fn crash(&self){ panic!("crash") }
+    }
+    let mut live = ActorLive::new();
+    assert_eq!( live.input(3),     Ok(()));
+    assert_eq!( live.output(),     Ok(3));
+    assert_eq!( live.in_out(4),    Ok(4));
+
+    // the actor thread panics 
+    assert_eq!( live.crash(),  Ok(()));
+    assert!( live.output().is_err());
+    assert_eq!( live.input(5), Err(ActorError::SendClosed));
+    assert_eq!( live.output(), Err(ActorError::SendClosed));
+}
+
+#[test]
+fn actor_sync_bounded_fallible() {
+    pub struct Actor(i8);
+    #[life(channel=2, fallible)]
+    impl Actor {
+        pub fn new() -> Self{Self(0)}
+        pub fn add(&mut self, v:i8) -> i8{self.0 += v;self.0}
+        pub fn crash(&self) -> i8 { panic!("crash") }
+    }
+    let live = ActorLive::new();
+    let mut live_a = live.clone();
+    assert_eq!( live_a.add(5), Ok(5));
+    assert_eq!( live.crash(), Err(ActorError::ReplyDropped));
+    assert!( live_a.add(5).is_err());
+    assert_eq!( live_a.add(5), Err(ActorError::SendClosed));
+    assert_eq!( ActorError::SendClosed.to_string(), "'ActorLive' sending on a closed channel.");
+}
+
+pub struct Aa(i8);
+impl Aa {
+    pub fn add(&mut self, v:i8) -> i8{ self.0 += v; self.0 }
+    pub fn crash(&self) -> i8 { panic!("crash") }
+}
+pub struct AaBb{ pub a: Aa }
+#[group( file = "tests/fallible.rs", fallible )]
+impl AaBb {
+    pub fn new() -> Self { Self{ a: Aa(0) } }
+    pub fn value(&self) -> i8 { self.a.0 }
+}
+
+#[test]
+fn group_sync_fallible() {
+    let mut group = AaBbGroupLive::new();
+    assert_eq!( group.a.add(2), Ok(2));
+    assert_eq!( group.value(),  Ok(2));
+    // the group thread panics 
+    assert_eq!( group.a.crash(), Err(AaErrorGroup::ReplyDropped));
+    assert!( group.value().is_err());
+    assert_eq!( group.a.add(2), Err(AaErrorGroup::SendClosed));
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_fallible() {
+    pub struct Actor(i8);
+    #[life(channel=2, lib="tokio", fallible)]
+    impl Actor {
+        pub fn new() -> Self{Self(0)}
+        pub fn input(&mut self, v:i8){self.0 = v}
+        pub fn output(&self)->i8{self.0}
+        pub fn crash(&self) -> i8 { panic!("crash") }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let mut live = ActorLive::new();
+        assert_eq!( live.input(3).await, Ok(()));
+        assert_eq!( live.output().await, Ok(3));
+        assert_eq!( live.crash().await,  Err(ActorError::ReplyDropped));
+        assert!( live.output().await.is_err());
+        assert_eq!( live.input(3).await, Err(ActorError::SendClosed));
+    });
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_fallible() {
+    pub struct Actor(i8);
+    #[life(lib="async_std", fallible)]
+    impl Actor {
+        pub fn new() -> Self{Self(0)}
+        pub fn input(&mut self, v:i8){self.0 = v}
+        pub fn output(&self)->i8{self.0}
+        pub fn crash(&self) -> i8 { panic!("crash") }
+    }
+    async_std::task::block_on(async {
+        let mut live = ActorLive::new();
+        assert_eq!( live.input(3).await, Ok(()));
+        assert_eq!( live.output().await, Ok(3));
+        assert_eq!( live.crash().await,  Err(ActorError::ReplyDropped));
+        assert!( live.output().await.is_err());
+        assert_eq!( live.input(3).await, Err(ActorError::SendClosed));
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_fallible() {
+    pub struct Actor(i8);
+    #[life(lib="smol", fallible)]
+    impl Actor {
+        pub fn new() -> Self{Self(0)}
+        pub fn input(&mut self, v:i8){self.0 = v}
+        pub fn output(&self)->i8{self.0}
+        pub fn crash(&self) -> i8 { panic!("crash") }
+    }
+    smol::block_on(async {
+        let mut live = ActorLive::new();
+        assert_eq!( live.input(3).await, Ok(()));
+        assert_eq!( live.output().await, Ok(3));
+        assert_eq!( live.crash().await,  Err(ActorError::ReplyDropped));
+        assert!( live.output().await.is_err());
+        assert_eq!( live.input(3).await, Err(ActorError::SendClosed));
+    });
+}
@@ -0,0 +1,143 @@
+use interthread::actor as life;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize,Ordering};
+use std::time::{Duration,Instant};
+
+// STD
+#[test]
+fn actor_sync_handle() {
+    pub struct Actor(i8);
+    #[life(handle)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn input(&mut self, v:i8){ self.0 = v }
+        pub fn output(&self)->i8{ self.0 }
+    }
+    let mut live = ActorLive::new();
+    live.input(3);
+    assert_eq!( live.output(), 3);
+
+    let handle = live.inter_take_handle().unwrap();
+    // shared between clones
+    assert!( live.clone().inter_take_handle().is_none());
+
+    drop(live);
+    assert!( handle.join().is_ok());
+}
+
+#[test]
+fn actor_sync_handle_panic() {
+    pub struct Actor;
+    #[life(handle)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn crash(&self){ panic!("crash") }
+    }
+    let live = ActorLive::new();
+    live.crash();
+    let payload = live.inter_take_handle().unwrap().join().unwrap_err();
+    assert_eq!( payload.downcast_ref::<&str>(), Some(&"crash"));
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_handle() {
+    pub struct Actor;
+    #[life(lib = "tokio", handle, shutdown)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn crash(&self){ panic!("crash") }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let live = ActorLive::new();
+        let handle = live.inter_take_handle().unwrap();
+        live.inter_shutdown().await;
+        assert!( handle.await.is_ok());
+
+        let live = ActorLive::new();
+        live.crash().await;
+        let error = live.inter_take_handle().unwrap().await.unwrap_err();
+        assert!( error.is_panic());
+    });
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_handle() {
+    pub struct Actor(i8);
+    #[life(lib = "async_std", handle)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn input(&mut self, v:i8){ self.0 = v }
+    }
+    async_std::task::block_on(async {
+        let mut live = ActorLive::new();
+        live.input(3).await;
+        let handle = live.inter_take_handle().unwrap();
+        drop(live);
+        handle.await;
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_handle() {
+    pub struct Actor(i8);
+    #[life(lib = "smol", handle)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn input(&mut self, v:i8){ self.0 = v }
+        pub fn output(&self)->i8{ self.0 }
+    }
+    smol::block_on(async {
+        let mut live = ActorLive::new();
+        live.input(3).await;
+        assert_eq!( live.output().await, 3);
+        let handle = live.inter_take_handle().unwrap();
+        drop(live);
+        handle.await;
+    });
+}
+
+#[test]
+fn actor_smol_handle_detach() {
+    pub struct Actor(i8);
+    #[life(lib = "smol", handle)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn input(&mut self, v:i8){ self.0 = v }
+        pub fn output(&self)->i8{ self.0 }
+    }
+    smol::block_on(async {
+        let mut live = ActorLive::new();
+        // the detached actor keeps running
+        live.inter_take_handle().unwrap().detach();
+        live.input(3).await;
+        assert_eq!( live.output().await, 3);
+        assert!( live.inter_take_handle().is_none());
+    });
+}
+
+#[test]
+fn actor_smol_handle_not_taken() {
+    pub struct Actor(Arc<AtomicUsize>);
+    #[life(lib = "smol", handle)]
+    impl Actor {
+        pub fn new(count: Arc<AtomicUsize>) -> Self{ Self(count) }
+        pub fn inc(&mut self){ std::thread::sleep(Duration::from_millis(1)); self.0.fetch_add(1,Ordering::SeqCst); }
+    }
+    let count = Arc::new(AtomicUsize::new(0));
+    smol::block_on(async {
+        let mut live = ActorLive::new(count.clone());
+        for _ in 0..10 { live.inc().await; }
+        // the task is detached, the queued messages are handled
+        drop(live);
+    });
+    let start = Instant::now();
+    while count.load(Ordering::SeqCst) < 10 && start.elapsed() < Duration::from_secs(5) {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    assert_eq!( count.load(Ordering::SeqCst), 10);
+}
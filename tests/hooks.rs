@@ -0,0 +1,114 @@
+use interthread::actor as life;
+use std::sync::{Arc,Mutex};
+
+// STD
+#[test]
+fn actor_sync_hooks() {
+    pub struct Actor{ log: Arc<Mutex<Vec<String>>>, thread: Option<std::thread::ThreadId> }
+    #[life(hooks(start = on_start, stop = on_stop, before = "before", after = "after"), shutdown)]
+    impl Actor {
+        pub fn new(log: Arc<Mutex<Vec<String>>>) -> Self{ Self{ log, thread: None } }
+        pub fn input(&mut self, v:i8){ self.log(format!("input {v}")) }
+        pub fn thread(&self)->Option<std::thread::ThreadId>{ self.thread }
+        fn log(&self, s: String){ self.log.lock().unwrap().push(s) }
+        fn on_start(&mut self){ self.thread = Some(std::thread::current().id()); self.log("start".into()) }
+        fn on_stop(&mut self){ self.log("stop".into()) }
+        fn before(&mut self, variant: &'static str){ self.log(format!("before {variant}")) }
+        fn after(&mut self, variant: &'static str){ self.log(format!("after {variant}")) }
+    }
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let mut live = ActorLive::new(log.clone());
+    live.input(3);
+    // started on the actor's thread
+    let thread = live.thread();
+    assert!( thread.is_some());
+    assert_ne!( thread, Some(std::thread::current().id()));
+    live.inter_join();
+
+    assert_eq!( *log.lock().unwrap(), vec![
+        "start",
+        "before Input", "input 3", "after Input",
+        "before Thread", "after Thread",
+        "stop",
+    ]);
+}
+
+#[test]
+fn actor_sync_hooks_supervise() {
+    pub struct Actor(usize);
+    #[life(hooks(start = on_start), supervise)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn starts(&self)->usize{ self.0 }
+        pub fn crash(&self){ panic!("crash") }
+        fn on_start(&mut self){ self.0 += 1 }
+    }
+    let live = ActorLive::new();
+    assert_eq!( live.starts(), 1);
+    live.crash();
+    // the rebuilt actor is started again
+    assert_eq!( live.starts(), 1);
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_hooks() {
+    pub struct Actor(Arc<Mutex<Vec<&'static str>>>);
+    #[life(lib = "tokio", hooks(start = on_start, stop = on_stop, after = after), handle)]
+    impl Actor {
+        pub fn new(log: Arc<Mutex<Vec<&'static str>>>) -> Self{ Self(log) }
+        pub async fn input(&mut self, _v:i8){}
+        async fn on_start(&mut self){ self.0.lock().unwrap().push("start") }
+        async fn on_stop(&mut self){ self.0.lock().unwrap().push("stop") }
+        async fn after(&mut self, variant: &'static str){ self.0.lock().unwrap().push(variant) }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut live = ActorLive::new(log.clone());
+        live.input(3).await;
+        let handle = live.inter_take_handle().unwrap();
+        drop(live);
+        assert!( handle.await.is_ok());
+        assert_eq!( *log.lock().unwrap(), vec!["start","Input","stop"]);
+    });
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_hooks() {
+    pub struct Actor(Vec<&'static str>);
+    #[life(lib = "async_std", hooks(before = before))]
+    impl Actor {
+        pub fn new() -> Self{ Self(vec![]) }
+        pub fn input(&mut self, _v:i8){}
+        pub fn log(&self)->Vec<&'static str>{ self.0.clone() }
+        fn before(&mut self, variant: &'static str){ self.0.push(variant) }
+    }
+    async_std::task::block_on(async {
+        let mut live = ActorLive::new();
+        live.input(3).await;
+        assert_eq!( live.log().await, vec!["Input","Log"]);
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_hooks() {
+    pub struct Actor(Arc<Mutex<Vec<&'static str>>>);
+    #[life(lib = "smol", hooks(start = on_start, stop = on_stop), shutdown)]
+    impl Actor {
+        pub fn new(log: Arc<Mutex<Vec<&'static str>>>) -> Self{ Self(log) }
+        pub fn input(&mut self, _v:i8){}
+        fn on_start(&mut self){ self.0.lock().unwrap().push("start") }
+        fn on_stop(&mut self){ self.0.lock().unwrap().push("stop") }
+    }
+    smol::block_on(async {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut live = ActorLive::new(log.clone());
+        live.input(3).await;
+        live.inter_join().await;
+        assert_eq!( *log.lock().unwrap(), vec!["start","stop"]);
+    });
+}
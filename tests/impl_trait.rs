@@ -0,0 +1,87 @@
+use interthread::actor as life;
+
+// STD
+#[test]
+fn actor_sync_impl_trait() {
+    pub struct Actor(u32);
+    #[life]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn apply(&mut self, f: impl Fn(u32)->u32 + Send + 'static)->u32{ self.0 = f(self.0); self.0 }
+        pub fn set_with(&mut self, mut f: impl FnMut()->u32){ self.0 = f() }
+        pub fn range(&self)-> impl Iterator<Item=u32> + Send { 0..self.0 }
+        pub fn show(&self, v: impl std::fmt::Display)->String{ v.to_string() }
+        pub fn boxed(&self, f: Box<dyn Fn(u32)->u32 + Send>)->u32{ f(self.0) }
+    }
+    let mut live = ActorLive::new();
+    assert_eq!( live.apply(|x| x + 3), 3);
+    live.set_with(|| 4);
+    assert_eq!( live.range().collect::<Vec<_>>(), vec![0,1,2,3]);
+    assert_eq!( live.show(5), "5".to_string());
+    assert_eq!( live.boxed(Box::new(|x| x * 2)), 8);
+}
+
+#[test]
+fn actor_sync_dyn_send_supertrait() {
+    pub trait Shape: Send { fn area(&self) -> u32; }
+    pub struct Square(u32);
+    impl Shape for Square { fn area(&self) -> u32 { self.0 * self.0 } }
+    pub struct Actor(u32);
+    #[life]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn add(&mut self, s: Box<dyn Shape>)->u32{ self.0 += s.area(); self.0 }
+    }
+    let mut live = ActorLive::new();
+    assert_eq!( live.add(Box::new(Square(3))), 9);
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_impl_trait() {
+    pub struct Actor(Vec<u32>);
+    #[life(lib = "tokio", fallible)]
+    impl Actor {
+        pub fn new() -> Self{ Self(vec![]) }
+        pub async fn extend(&mut self, items: impl Iterator<Item=u32>){ self.0.extend(items) }
+        pub fn items(&self)-> impl Iterator<Item=u32> + Send { self.0.clone().into_iter() }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let mut live = ActorLive::new();
+        assert_eq!( live.extend(vec![1,2].into_iter()).await, Ok(()));
+        assert_eq!( live.items().await.map(|i| i.sum::<u32>()), Ok(3));
+    });
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_impl_trait() {
+    pub struct Actor(u32);
+    #[life(lib = "async_std")]
+    impl Actor {
+        pub fn new() -> Self{ Self(1) }
+        pub fn apply(&mut self, f: impl FnOnce(u32)->u32)->u32{ self.0 = f(self.0); self.0 }
+    }
+    async_std::task::block_on(async {
+        let mut live = ActorLive::new();
+        let s = String::from("abc");
+        assert_eq!( live.apply(move |x| x + s.len() as u32).await, 4);
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_impl_trait() {
+    pub struct Actor;
+    #[life(lib = "smol")]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn label(&self)-> impl std::fmt::Display + Send { 42 }
+    }
+    smol::block_on(async {
+        let live = ActorLive::new();
+        assert_eq!( live.label().await.to_string(), "42".to_string());
+    });
+}
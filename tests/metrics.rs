@@ -0,0 +1,130 @@
+use interthread::actor as life;
+use std::time::Duration;
+
+// STD
+#[test]
+fn actor_sync_metrics() {
+    pub struct Actor(i8);
+    #[life(metrics)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn input(&mut self, v:i8){ self.0 = v }
+        pub fn output(&self)->i8{ self.0 }
+        pub fn sleep(&self, ms: u64){ std::thread::sleep(Duration::from_millis(ms)) }
+    }
+    let mut live = ActorLive::new();
+    live.input(1);
+    live.input(2);
+    assert_eq!( live.output(), 2);
+    live.sleep(20);
+    live.input(3);
+    live.input(4);
+    // the input messages wait for the sleep
+    assert!( live.metrics().queue_depth >= 2);
+    assert_eq!( live.output(), 4);
+
+    // the last reply may arrive before its message is counted
+    let metrics = live.clone().metrics();
+    assert_eq!( metrics.queue_depth, 0);
+    assert_eq!( metrics.processed.get("Input"), Some(&4));
+    assert!( metrics.processed.get("Output") >= Some(&1));
+    assert!( metrics.elapsed["Sleep"] >= Duration::from_millis(20));
+    assert!( metrics.elapsed["Sleep"] > metrics.elapsed["Input"]);
+}
+
+#[test]
+fn actor_sync_metrics_pool() {
+    pub struct Actor;
+    #[life(metrics, pool(size = 2))]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn ping(&self)->bool{ true }
+    }
+    let live = ActorLive::new();
+    for _ in 0..4 { assert!( live.ping()); }
+    // shared by the instances
+    while live.metrics().processed.get("Ping") != Some(&4) { std::thread::yield_now(); }
+    assert_eq!( live.metrics().queue_depth, 0);
+}
+
+#[test]
+fn actor_sync_metrics_try_send() {
+    pub struct Actor(u8);
+    #[life(metrics, channel = 1, try_send)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn set(&mut self, v: u8){ std::thread::sleep(Duration::from_millis(5)); self.0 = v }
+        pub fn get(&self)->u8{ self.0 }
+    }
+    let mut live = ActorLive::new();
+    let sent = (0..10).filter(|v| live.try_set(*v).is_ok()).count();
+    assert!( sent < 10);
+    live.get();
+    // the failed sends are not queued
+    assert_eq!( live.metrics().queue_depth, 0);
+    assert_eq!( live.metrics().processed["Set"], sent as u64);
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_metrics() {
+    pub struct Actor(u8);
+    #[life(lib = "tokio", metrics, channel = 2, hooks(before = before))]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub async fn add(&mut self, v:u8)->u8{ self.0 += v; self.0 }
+        pub fn get(&self)->u8{ self.0 }
+        fn before(&mut self, _variant: &'static str){ self.0 += 1 }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let mut live = ActorLive::new();
+        assert_eq!( live.add(1).await, 2);
+        assert_eq!( live.add(1).await, 4);
+        assert_eq!( live.get().await, 5);
+        let metrics = live.metrics();
+        assert_eq!( metrics.processed["Add"], 2);
+        assert_eq!( metrics.queue_depth, 0);
+    });
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_metrics() {
+    pub struct Actor;
+    #[life(lib = "async_std", metrics, fallible)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn ping(&self)->bool{ true }
+        pub fn pong(&self)->bool{ false }
+    }
+    async_std::task::block_on(async {
+        let live = ActorLive::new();
+        assert_eq!( live.ping().await, Ok(true));
+        assert_eq!( live.pong().await, Ok(false));
+        assert_eq!( live.metrics().processed["Ping"], 1);
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_metrics() {
+    pub struct Actor;
+    #[life(lib = "smol", metrics, shutdown)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn ping(&self)->bool{ true }
+    }
+    smol::block_on(async {
+        let live = ActorLive::new();
+        let observer = live.clone();
+        assert!( live.ping().await);
+        live.inter_join().await;
+        // the control messages are not counted
+        let metrics = observer.metrics();
+        assert_eq!( metrics.processed.len(), 1);
+        assert_eq!( metrics.processed["Ping"], 1);
+        assert_eq!( metrics.queue_depth, 0);
+    });
+}
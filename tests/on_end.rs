@@ -0,0 +1,123 @@
+use interthread::actor as life;
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+// STD
+#[test]
+fn actor_sync_on_end_callback() {
+    pub struct Actor;
+    #[life(on_end = on_actor_end, handle)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn ping(&self)->bool{ true }
+    }
+    static ENDS: Mutex<Vec<(&'static str,Option<SystemTime>,ActorEnd)>> = Mutex::new(Vec::new());
+    fn on_actor_end(name: &'static str, debut: Option<SystemTime>, reason: ActorEnd){
+        ENDS.lock().unwrap().push((name,debut,reason));
+    }
+    let live = ActorLive::new();
+    let handle = live.inter_take_handle().unwrap();
+    assert!( live.ping());
+    drop(live);
+    assert!( handle.join().is_ok());
+    assert_eq!( *ENDS.lock().unwrap(), vec![("Actor",None,ActorEnd::Closed)]);
+}
+
+#[test]
+fn actor_sync_on_end_panic() {
+    pub struct Actor;
+    #[life(on_end = on_actor_end, handle, debut)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn crash(&self){ panic!("crash") }
+    }
+    static ENDS: Mutex<Vec<(Option<SystemTime>,ActorEnd)>> = Mutex::new(Vec::new());
+    fn on_actor_end(_name: &'static str, debut: Option<SystemTime>, reason: ActorEnd){
+        ENDS.lock().unwrap().push((debut,reason));
+    }
+    let live = ActorLive::new();
+    let handle = live.inter_take_handle().unwrap();
+    let debut = live.inter_get_debut();
+    live.crash();
+    assert!( handle.join().is_err());
+    assert_eq!( *ENDS.lock().unwrap(), vec![(Some(debut),ActorEnd::Panicked)]);
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_on_end_log() {
+    static RECORDS: Mutex<Vec<(log::Level,String)>> = Mutex::new(Vec::new());
+    struct Logger;
+    impl log::Log for Logger {
+        fn enabled(&self, _: &log::Metadata) -> bool { true }
+        fn log(&self, record: &log::Record) {
+            RECORDS.lock().unwrap().push((record.level(), record.args().to_string()));
+        }
+        fn flush(&self) {}
+    }
+    log::set_logger(&Logger).unwrap();
+    log::set_max_level(log::LevelFilter::Info);
+
+    pub struct Actor;
+    #[life(lib = "tokio", on_end = "log", handle)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn crash(&self){ panic!("crash") }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let live = ActorLive::new();
+        let handle = live.inter_take_handle().unwrap();
+        drop(live);
+        assert!( handle.await.is_ok());
+
+        let live = ActorLive::new();
+        let handle = live.inter_take_handle().unwrap();
+        live.crash().await;
+        assert!( handle.await.is_err());
+    });
+    assert_eq!( *RECORDS.lock().unwrap(), vec![
+        (log::Level::Info, "Actor the end ...".to_string()),
+        (log::Level::Error,"Actor panicked ...".to_string()),
+    ]);
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_on_end() {
+    pub struct Actor;
+    #[life(lib = "async_std", on_end = on_actor_end, shutdown)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn ping(&self)->bool{ true }
+    }
+    static ENDS: Mutex<Vec<ActorEnd>> = Mutex::new(Vec::new());
+    fn on_actor_end(_name: &'static str, _debut: Option<SystemTime>, reason: ActorEnd){
+        ENDS.lock().unwrap().push(reason);
+    }
+    async_std::task::block_on(async {
+        let live = ActorLive::new();
+        assert!( live.ping().await);
+        live.inter_join().await;
+    });
+    assert_eq!( *ENDS.lock().unwrap(), vec![ActorEnd::Closed]);
+}
+
+// SMOL
+#[test]
+fn actor_smol_on_end_silent() {
+    pub struct Actor;
+    #[life(lib = "smol", on_end = "silent", handle)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn ping(&self)->bool{ true }
+    }
+    smol::block_on(async {
+        let live = ActorLive::new();
+        let handle = live.inter_take_handle().unwrap();
+        assert!( live.ping().await);
+        drop(live);
+        handle.await;
+    });
+}
@@ -0,0 +1,128 @@
+use interthread::actor as life;
+use std::sync::{Arc,Mutex};
+use std::sync::atomic::{AtomicUsize,Ordering};
+
+// STD
+#[test]
+fn actor_sync_pool_round_robin() {
+    pub struct Actor{ id: usize, count: usize }
+    #[life(pool(size = 3))]
+    impl Actor {
+        pub fn new(ids: Arc<AtomicUsize>) -> Self{ Self{ id: ids.fetch_add(1,Ordering::SeqCst), count: 0 } }
+        pub fn id(&mut self)->(usize,usize){ self.count += 1; (self.id,self.count) }
+    }
+    let ids = Arc::new(AtomicUsize::new(0));
+    let mut live = ActorLive::new(ids.clone());
+    // each instance is created by `new`
+    assert_eq!( ids.load(Ordering::SeqCst), 3);
+
+    let mut ids = (0..6).map(|_| live.id()).collect::<Vec<_>>();
+    // shared between clones
+    ids.extend((0..3).map(|_| live.clone().id()));
+    ids.sort();
+    assert_eq!( ids, vec![(0,1),(0,2),(0,3),(1,1),(1,2),(1,3),(2,1),(2,2),(2,3)]);
+}
+
+#[test]
+fn actor_sync_pool_key_hash() {
+    pub struct Actor(std::collections::HashMap<String,i32>);
+    #[life(pool(size = 4, dispatch = "key_hash", key = name), cast)]
+    impl Actor {
+        pub fn new() -> Self{ Self(Default::default()) }
+        pub fn insert(&mut self, name: String, v: i32){ self.0.insert(name,v); }
+        pub fn get(&self, name: String)->Option<i32>{ self.0.get(&name).cloned() }
+        pub fn len(&self)->usize{ self.0.len() }
+    }
+    let mut live = ActorLive::new();
+    for (i,name) in ["a","b","c","d","e","f"].iter().enumerate() {
+        live.insert(name.to_string(), i as i32);
+    }
+    // the same key is sent to the same instance
+    for (i,name) in ["a","b","c","d","e","f"].iter().enumerate() {
+        assert_eq!( live.get(name.to_string()), Some(i as i32));
+    }
+    live.cast_get("a".to_string());
+    // not keyed, round robin
+    assert_eq!( (0..4).map(|_| live.len()).sum::<usize>(), 6);
+}
+
+#[test]
+fn actor_sync_pool_least_queued() {
+    pub struct Actor{ id: usize, gates: Arc<Vec<Mutex<()>>> }
+    #[life(pool(size = 2, dispatch = "least_queued"))]
+    impl Actor {
+        pub fn new(ids: Arc<AtomicUsize>, gates: Arc<Vec<Mutex<()>>>) -> Self{
+            Self{ id: ids.fetch_add(1,Ordering::SeqCst), gates }
+        }
+        pub fn pause(&self){ drop(self.gates[self.id].lock().unwrap()); }
+        pub fn id(&self)->usize{ self.id }
+    }
+    let gates = Arc::new(vec![Mutex::new(()),Mutex::new(())]);
+    let live = ActorLive::new(Arc::new(AtomicUsize::new(0)), gates.clone());
+    let gate_0 = gates[0].lock().unwrap();
+    let gate_1 = gates[1].lock().unwrap();
+    // both instances are busy, the third message 
+    // is queued by the first instance (a tie)
+    live.pause();
+    live.pause();
+    live.pause();
+    drop(gate_1);
+    // the first instance has two messages, the second at most one 
+    for _ in 0..5 {
+        assert_eq!( live.id(), 1);
+    }
+    drop(gate_0);
+    while live.id() != 0 { std::thread::yield_now(); }
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_pool() {
+    pub struct Actor(std::collections::HashMap<u32,u32>);
+    #[life(lib = "tokio", pool(size = 2, dispatch = "key_hash", key = "key"), fallible)]
+    impl Actor {
+        pub fn new() -> Self{ Self(Default::default()) }
+        pub async fn add(&mut self, key: u32, v: u32)->u32{ *self.0.entry(key).or_default() += v; self.0[&key] }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let mut live = ActorLive::new();
+        for key in 0..8 {
+            assert_eq!( live.add(key, 1).await, Ok(1));
+            assert_eq!( live.add(key, 2).await, Ok(3));
+        }
+    });
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_pool() {
+    pub struct Actor(usize);
+    #[life(lib = "async_std", pool(size = 2))]
+    impl Actor {
+        pub fn new(ids: Arc<AtomicUsize>) -> Self{ Self(ids.fetch_add(1,Ordering::SeqCst)) }
+        pub fn id(&self)->usize{ self.0 }
+    }
+    async_std::task::block_on(async {
+        let live = ActorLive::new(Arc::new(AtomicUsize::new(0)));
+        assert_eq!( live.id().await + live.id().await, 1);
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_pool() {
+    pub struct Actor(usize);
+    #[life(lib = "smol", pool(size = 2, dispatch = "least_queued"))]
+    impl Actor {
+        pub fn try_new(ids: Arc<AtomicUsize>) -> Result<Self,String>{ Ok(Self(ids.fetch_add(1,Ordering::SeqCst))) }
+        pub fn id(&self)->usize{ self.0 }
+    }
+    smol::block_on(async {
+        let ids = Arc::new(AtomicUsize::new(0));
+        let live = ActorLive::try_new(ids.clone()).unwrap();
+        assert!( live.id().await < 2);
+        assert_eq!( ids.load(Ordering::SeqCst), 2);
+    });
+}
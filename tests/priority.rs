@@ -0,0 +1,130 @@
+use interthread::actor as life;
+use std::sync::{Arc,Barrier};
+
+// STD
+#[test]
+fn actor_sync_priority() {
+    pub struct Actor(Vec<String>, Arc<Barrier>);
+    #[life(priority(high(cancel, status)))]
+    impl Actor {
+        pub fn new(barrier: Arc<Barrier>) -> Self{ Self(vec![],barrier) }
+        pub fn push(&mut self, s: &'static str){ self.0.push(s.to_string()) }
+        pub fn cancel(&mut self){ self.0.push("cancel".to_string()) }
+        pub fn status(&self)->usize{ self.0.len() }
+        pub fn log(&self)->Vec<String>{ self.0.clone() }
+        pub fn pause(&self){ self.1.wait(); self.1.wait(); }
+    }
+    let barrier = Arc::new(Barrier::new(2));
+    let mut live = ActorLive::new(barrier.clone());
+
+    // the actor is busy 
+    live.pause();
+    barrier.wait();
+    live.push("a");
+    live.push("b");
+    live.cancel();
+    barrier.wait();
+
+    assert_eq!( live.log(), vec!["cancel","a","b"]);
+    assert_eq!( live.status(), 3);
+}
+
+#[test]
+fn actor_sync_priority_bounded() {
+    pub struct Actor(Vec<String>, Arc<Barrier>);
+    #[life(channel = 2, fallible, priority(high(cancel)))]
+    impl Actor {
+        pub fn new(barrier: Arc<Barrier>) -> Self{ Self(vec![],barrier) }
+        pub fn push(&mut self, s: &'static str){ self.0.push(s.to_string()) }
+        pub fn cancel(&mut self){ self.0.push("cancel".to_string()) }
+        pub fn log(&self)->Vec<String>{ self.0.clone() }
+        pub fn pause(&self){ self.1.wait(); self.1.wait(); }
+    }
+    let barrier = Arc::new(Barrier::new(2));
+    let mut live = ActorLive::new(barrier.clone());
+
+    assert_eq!( live.pause(), Ok(()));
+    barrier.wait();
+    // the channel is full, the wake message is dropped 
+    assert_eq!( live.push("a"), Ok(()));
+    assert_eq!( live.push("b"), Ok(()));
+    assert_eq!( live.cancel(), Ok(()));
+    barrier.wait();
+    assert_eq!( live.log(), Ok(vec!["cancel".to_string(),"a".to_string(),"b".to_string()]));
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_priority() {
+    pub struct Actor(Vec<String>, Arc<Barrier>);
+    #[life(lib = "tokio", priority(high(cancel)))]
+    impl Actor {
+        pub fn new(barrier: Arc<Barrier>) -> Self{ Self(vec![],barrier) }
+        pub fn push(&mut self, s: &'static str){ self.0.push(s.to_string()) }
+        pub fn cancel(&mut self){ self.0.push("cancel".to_string()) }
+        pub fn log(&self)->Vec<String>{ self.0.clone() }
+        pub fn pause(&self){ self.1.wait(); self.1.wait(); }
+    }
+    tokio::runtime::Builder::new_multi_thread()
+    .worker_threads(2)
+    .build()
+    .unwrap()
+    .block_on(async {
+        let barrier = Arc::new(Barrier::new(2));
+        let mut live = ActorLive::new(barrier.clone());
+        live.pause().await;
+        barrier.wait();
+        live.push("a").await;
+        live.cancel().await;
+        barrier.wait();
+        assert_eq!( live.log().await, vec!["cancel","a"]);
+    });
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_priority() {
+    pub struct Actor(Vec<String>, Arc<Barrier>);
+    #[life(lib = "async_std", priority(high(cancel)))]
+    impl Actor {
+        pub fn new(barrier: Arc<Barrier>) -> Self{ Self(vec![],barrier) }
+        pub fn push(&mut self, s: &'static str){ self.0.push(s.to_string()) }
+        pub fn cancel(&mut self){ self.0.push("cancel".to_string()) }
+        pub fn log(&self)->Vec<String>{ self.0.clone() }
+        pub fn pause(&self){ self.1.wait(); self.1.wait(); }
+    }
+    async_std::task::block_on(async {
+        let barrier = Arc::new(Barrier::new(2));
+        let mut live = ActorLive::new(barrier.clone());
+        live.pause().await;
+        barrier.wait();
+        live.push("a").await;
+        live.cancel().await;
+        barrier.wait();
+        assert_eq!( live.log().await, vec!["cancel","a"]);
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_priority() {
+    pub struct Actor(Vec<String>, Arc<Barrier>);
+    #[life(lib = "smol", channel = 4, priority(high(cancel)))]
+    impl Actor {
+        pub fn new(barrier: Arc<Barrier>) -> Self{ Self(vec![],barrier) }
+        pub fn push(&mut self, s: &'static str){ self.0.push(s.to_string()) }
+        pub fn cancel(&mut self){ self.0.push("cancel".to_string()) }
+        pub fn log(&self)->Vec<String>{ self.0.clone() }
+        pub fn pause(&self){ self.1.wait(); self.1.wait(); }
+    }
+    smol::block_on(async {
+        let barrier = Arc::new(Barrier::new(2));
+        let mut live = ActorLive::new(barrier.clone());
+        live.pause().await;
+        barrier.wait();
+        live.push("a").await;
+        live.cancel().await;
+        barrier.wait();
+        assert_eq!( live.log().await, vec!["cancel","a"]);
+    });
+}
@@ -0,0 +1,110 @@
+use interthread::actor as life;
+
+// STD
+#[test]
+fn actor_sync_registry() {
+    pub struct Actor(i8);
+    #[life(registry, shutdown)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn input(&mut self, v:i8){ self.0 = v }
+        pub fn output(&self)->i8{ self.0 }
+    }
+    let mut live = ActorLive::new();
+    assert!( live.register("service").is_none());
+    live.input(3);
+    assert!( ActorLive::lookup("unknown").is_none());
+    assert_eq!( ActorLive::lookup("service").map(|l| l.output()), Some(3));
+    assert!( live.register("alias").is_none());
+    assert_eq!( ActorLive::registered(), vec!["alias","service"]);
+
+    // deregistered when the actor stops
+    live.inter_shutdown();
+    live.inter_join();
+    assert!( ActorLive::registered().is_empty());
+}
+
+#[test]
+fn actor_sync_registry_panic() {
+    pub struct Actor;
+    #[life(registry, handle)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn crash(&self){ panic!("crash") }
+    }
+    let live = ActorLive::new();
+    let handle = live.inter_take_handle().unwrap();
+    live.register("service");
+    live.crash();
+    assert!( handle.join().is_err());
+    assert!( ActorLive::lookup("service").is_none());
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_registry() {
+    pub struct Actor(u8);
+    #[life(lib = "tokio", registry, handle)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub async fn add(&mut self, v:u8)->u8{ self.0 += v; self.0 }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let live = ActorLive::new();
+        let handle = live.inter_take_handle().unwrap();
+        live.register("counter");
+        drop(live);
+
+        let task = tokio::spawn(async {
+            ActorLive::lookup("counter").unwrap().add(1).await
+        });
+        assert_eq!( task.await.unwrap(), 1);
+        assert_eq!( ActorLive::lookup("counter").unwrap().add(1).await, 2);
+
+        // the registry keeps the actor alive until deregistered
+        assert!( ActorLive::deregister("counter").is_some());
+        assert!( handle.await.is_ok());
+        assert!( ActorLive::registered().is_empty());
+    });
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_registry() {
+    pub struct Actor(u8);
+    #[life(lib = "async_std", registry, shutdown)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn add(&mut self, v:u8)->u8{ self.0 += v; self.0 }
+    }
+    async_std::task::block_on(async {
+        let live = ActorLive::new();
+        live.register("counter");
+        assert_eq!( ActorLive::lookup("counter").unwrap().add(2).await, 2);
+        live.inter_join().await;
+        assert!( ActorLive::lookup("counter").is_none());
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_registry() {
+    pub struct Actor(u8);
+    #[life(lib = "smol", registry, weak)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn add(&mut self, v:u8)->u8{ self.0 += v; self.0 }
+    }
+    smol::block_on(async {
+        let live = ActorLive::new();
+        let weak = live.downgrade();
+        live.register("counter");
+        drop(live);
+        // registered, the actor is alive
+        assert_eq!( weak.upgrade().unwrap().add(2).await, 2);
+        ActorLive::deregister("counter");
+        assert!( weak.upgrade().is_none());
+    });
+}
@@ -0,0 +1,150 @@
+use interthread::actor as life;
+
+// STD
+#[test]
+fn actor_sync_remote() {
+    pub struct Actor(Vec<String>);
+    #[life(serde, remote)]
+    impl Actor {
+        pub fn new() -> Self{ Self(vec![]) }
+        pub fn push(&mut self, s: String){ self.0.push(s) }
+        pub fn join(&self, sep: String, upper: bool) -> String { 
+            let s = self.0.join(&sep);
+            if upper { s.to_uppercase() } else { s }
+        }
+        pub fn count(&self)->usize{ self.0.len() }
+        pub fn clear(&mut self){ self.0.clear() }
+    }
+    let (client, server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let live = ActorLive::new();
+    let server = std::thread::spawn(move || ActorServer::new(live, server).serve());
+
+    let remote = ActorRemote::new(client);
+    remote.push("a".to_string()).unwrap();
+    remote.clone().push("b".to_string()).unwrap();
+    assert_eq!( remote.count().unwrap(), 2);
+    assert_eq!( remote.join("-".to_string(), true).unwrap(), "A-B");
+    remote.clear().unwrap();
+    assert_eq!( remote.count().unwrap(), 0);
+
+    // the server returns once the client is dropped
+    drop(remote);
+    assert!( server.join().unwrap().is_ok());
+}
+
+#[test]
+fn actor_sync_remote_closed() {
+    pub struct Actor;
+    #[life(serde, remote)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn ping(&self)->bool{ true }
+    }
+    let (client, server) = std::os::unix::net::UnixStream::pair().unwrap();
+    drop(server);
+    let remote = ActorRemote::new(client);
+    // a transport error instead of a panic
+    assert!( remote.ping().is_err());
+    assert!( ActorLive::new().ping());
+}
+
+#[test]
+fn actor_sync_remote_live_error() {
+    pub struct Actor;
+    #[life(serde, remote, fallible)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn ping(&self)->bool{ true }
+        pub fn crash(&self)->bool{ panic!("crash") }
+    }
+    let (client, server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let live = ActorLive::new();
+    std::thread::spawn(move || ActorServer::new(live, server).serve());
+    let remote = ActorRemote::new(client);
+    assert!( remote.ping().unwrap());
+    // the errors of the live instance are replies, 
+    // the connection is kept
+    let error = remote.crash().unwrap_err();
+    assert_eq!( error.kind(), std::io::ErrorKind::Other);
+    let error = remote.ping().unwrap_err();
+    assert_eq!( error.kind(), std::io::ErrorKind::Other);
+}
+
+#[test]
+fn actor_sync_remote_frame_limit() {
+    use std::io::Write;
+    pub struct Actor;
+    #[life(serde, remote)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn ping(&self)->bool{ true }
+    }
+    let (mut client, server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let server = std::thread::spawn(move || ActorServer::new(ActorLive::new(), server).serve());
+    client.write_all(&u32::MAX.to_be_bytes()).unwrap();
+    let error = server.join().unwrap().unwrap_err();
+    assert_eq!( error.kind(), std::io::ErrorKind::InvalidData);
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_remote() {
+    pub struct Actor(u8);
+    #[life(lib = "tokio", serde, remote, fallible)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub async fn add(&mut self, v:u8)->u8{ self.0 += v; self.0 }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let (client, server) = tokio::io::duplex(64);
+        let live = ActorLive::new();
+        let server = tokio::spawn(ActorServer::new(live, server).serve());
+        let remote = ActorRemote::new(client);
+        assert_eq!( remote.add(1).await.unwrap(), 1);
+        assert_eq!( remote.add(2).await.unwrap(), 3);
+        drop(remote);
+        assert!( server.await.unwrap().is_ok());
+    });
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_remote() {
+    pub struct Actor(u8);
+    #[life(lib = "async_std", serde, remote)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn add(&mut self, v:u8)->u8{ self.0 += v; self.0 }
+    }
+    async_std::task::block_on(async {
+        let (client, server) = async_std::os::unix::net::UnixStream::pair().unwrap();
+        let server = async_std::task::spawn(ActorServer::new(ActorLive::new(), server).serve());
+        let remote = ActorRemote::new(client);
+        assert_eq!( remote.add(2).await.unwrap(), 2);
+        drop(remote);
+        assert!( server.await.is_ok());
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_remote() {
+    pub struct Actor(u8);
+    #[life(lib = "smol", serde, remote)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn set(&mut self, v:u8){ self.0 = v }
+        pub fn get(&self)->u8{ self.0 }
+    }
+    smol::block_on(async {
+        let (client, server) = smol::Async::<std::os::unix::net::UnixStream>::pair().unwrap();
+        let server = smol::spawn(ActorServer::new(ActorLive::new(), server).serve());
+        let remote = ActorRemote::new(client);
+        remote.set(5).await.unwrap();
+        assert_eq!( remote.get().await.unwrap(), 5);
+        drop(remote);
+        assert!( server.await.is_ok());
+    });
+}
@@ -0,0 +1,141 @@
+use interthread::actor as life;
+
+fn journal(name: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("interthread_{name}_{}.jsonl", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+// STD
+#[test]
+fn actor_sync_serde() {
+    pub struct Actor(Vec<String>);
+    #[life(serde)]
+    impl Actor {
+        pub fn new() -> Self{ Self(vec![]) }
+        pub fn push(&mut self, s: String){ self.0.push(s) }
+        pub fn count(&self)->usize{ self.0.len() }
+    }
+    let msg = ActorScript::Push{ input: "a".to_string() };
+    let json = serde_json::to_string(&msg).unwrap();
+    assert_eq!( json, r#"{"Push":{"input":"a"}}"#);
+
+    // the sender is not serialized
+    let (send,_recv) = oneshot::channel::<usize>();
+    let json = serde_json::to_string(&ActorScript::Count{ inter_send: send }).unwrap();
+    assert_eq!( json, r#"{"Count":{}}"#);
+    let msg: ActorScript = serde_json::from_str(&json).unwrap();
+    assert!( matches!(msg, ActorScript::Count{..}));
+
+    let mut live = ActorLive::new();
+    live.push("b".to_string());
+    assert_eq!( live.count(), 1);
+}
+
+#[test]
+fn actor_sync_serde_journal() {
+    pub struct Actor(Vec<u32>);
+    #[life(serde(journal = "target/tmp_sync_journal.jsonl"), handle)]
+    impl Actor {
+        pub fn new() -> Self{ Self(vec![]) }
+        pub fn push(&mut self, v: u32, times: usize){ for _ in 0..times { self.0.push(v) } }
+        pub fn sum(&self)->u32{ self.0.iter().sum() }
+    }
+    let path = std::path::Path::new("target/tmp_sync_journal.jsonl");
+    let _ = std::fs::remove_file(path);
+
+    let mut live = ActorLive::new();
+    let handle = live.inter_take_handle().unwrap();
+    live.push(1,2);
+    live.push(5,1);
+    assert_eq!( live.sum(), 7);
+    drop(live);
+    handle.join().unwrap();
+
+    // a fresh actor gets the same state
+    let mut actor = Actor::new();
+    ActorScript::replay(path, &mut actor).unwrap();
+    assert_eq!( actor.0, vec![1,1,5]);
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn actor_sync_serde_journal_unavailable() {
+    pub struct Actor(u32);
+    #[life(serde(journal = "target/missing_dir/journal.jsonl"))]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn add(&mut self, v: u32)->u32{ self.0 += v; self.0 }
+    }
+    // the actor runs without a journal
+    let mut live = ActorLive::new();
+    assert_eq!( live.add(2), 2);
+    assert_eq!( live.add(3), 5);
+    assert!( !std::path::Path::new("target/missing_dir").exists());
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_serde() {
+    pub struct Actor(u8);
+    #[life(lib = "tokio", serde, fallible)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub async fn add(&mut self, v:u8)->u8{ self.0 += v; self.0 }
+    }
+    let path = journal("tokio");
+    std::fs::write(&path, "{\"Add\":{\"input\":2}}\n{\"Add\":{\"input\":3}}\n").unwrap();
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let mut actor = Actor::new();
+        ActorScript::replay(&path, &mut actor).await.unwrap();
+        assert_eq!( actor.0, 5);
+        // the live actor is not affected
+        let mut live = ActorLive::new();
+        assert_eq!( live.add(1).await, Ok(1));
+    });
+    let _ = std::fs::remove_file(&path);
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_serde() {
+    pub struct Actor(u8);
+    #[life(lib = "async_std", serde)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn add(&mut self, v:u8)->u8{ self.0 += v; self.0 }
+    }
+    let path = journal("async_std");
+    std::fs::write(&path, "{\"Add\":{\"input\":2}}\nnot json\n").unwrap();
+    async_std::task::block_on(async {
+        let mut actor = Actor::new();
+        // stops at the first invalid message
+        assert!( ActorScript::replay(&path, &mut actor).await.is_err());
+        assert_eq!( actor.0, 2);
+    });
+    let _ = std::fs::remove_file(&path);
+}
+
+// SMOL
+#[test]
+fn actor_smol_serde_journal() {
+    pub struct Actor(u8);
+    #[life(lib = "smol", serde(journal = "target/tmp_smol_journal.jsonl"), shutdown)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn add(&mut self, v:u8)->u8{ self.0 += v; self.0 }
+    }
+    let path = std::path::Path::new("target/tmp_smol_journal.jsonl");
+    let _ = std::fs::remove_file(path);
+    smol::block_on(async {
+        let mut live = ActorLive::new();
+        assert_eq!( live.add(4).await, 4);
+        live.inter_join().await;
+        let mut actor = Actor::new();
+        ActorScript::replay(path, &mut actor).await.unwrap();
+        assert_eq!( actor.0, 4);
+    });
+    let _ = std::fs::remove_file(path);
+}
@@ -0,0 +1,125 @@
+use interthread::{actor as life, group};
+use std::sync::{Arc,Mutex};
+
+// STD
+#[test]
+fn actor_sync_shutdown() {
+    pub struct Actor{ value: i8, log: Arc<Mutex<Vec<String>>> }
+    #[life(shutdown(on_stop = "stop"), fallible)]
+    impl Actor {
+        pub fn new(log: Arc<Mutex<Vec<String>>>) -> Self{ Self{ value: 0, log } }
+        pub fn input(&mut self, v:i8){ self.value = v }
+        pub fn output(&self)->i8{ self.value }
+        fn stop(&mut self){ self.log.lock().unwrap().push(format!("stop {}",self.value)) }
+    }
+    let log = Arc::new(Mutex::new(vec![]));
+    let mut live = ActorLive::new(log.clone());
+    let other = live.clone();
+    assert_eq!( live.input(3), Ok(()));
+
+    // the actor stops while `other` is still alive
+    live.inter_join();
+    assert_eq!( *log.lock().unwrap(), vec!["stop 3".to_string()]);
+    assert!( other.output().is_err());
+    assert_eq!( other.output(), Err(ActorError::SendClosed));
+}
+
+#[test]
+fn actor_sync_shutdown_queued() {
+    pub struct Actor(Arc<Mutex<Vec<i8>>>);
+    #[life(shutdown, channel = 4)]
+    impl Actor {
+        pub fn new(log: Arc<Mutex<Vec<i8>>>) -> Self{ Self(log) }
+        pub fn input(&mut self, v:i8){ self.0.lock().unwrap().push(v) }
+    }
+    let log = Arc::new(Mutex::new(vec![]));
+    let mut live = ActorLive::new(log.clone());
+    live.input(1);
+    live.input(2);
+    // messages sent before are handled
+    live.inter_shutdown();
+    let other = live.clone();
+    live.inter_join();
+    // joining a stopped actor returns
+    other.inter_join();
+    assert_eq!( *log.lock().unwrap(), vec![1,2]);
+}
+
+pub struct Aa(u8);
+impl Aa {
+    pub fn add(&mut self, v: u8){ self.0 += v; }
+}
+pub struct AaBb{ pub a: Aa, log: Arc<Mutex<Vec<u8>>> }
+#[group( file = "tests/shutdown.rs", shutdown(on_stop = "stop") )]
+impl AaBb {
+    pub fn new( log: Arc<Mutex<Vec<u8>>> ) -> Self { Self{ a: Aa(0), log } }
+    fn stop(&mut self){ self.log.lock().unwrap().push(self.a.0) }
+}
+
+#[test]
+fn group_sync_shutdown() {
+    let log = Arc::new(Mutex::new(vec![]));
+    let mut group = AaBbGroupLive::new(log.clone());
+    group.a.add(7);
+    group.inter_join();
+    assert_eq!( *log.lock().unwrap(), vec![7]);
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_shutdown() {
+    pub struct Actor(Arc<Mutex<Vec<i8>>>);
+    #[life(lib = "tokio", shutdown(on_stop = "stop"), channel = 2)]
+    impl Actor {
+        pub fn new(log: Arc<Mutex<Vec<i8>>>) -> Self{ Self(log) }
+        pub fn input(&mut self, v:i8){ self.0.lock().unwrap().push(v) }
+        fn stop(&mut self){ self.0.lock().unwrap().push(0) }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let log = Arc::new(Mutex::new(vec![]));
+        let mut live = ActorLive::new(log.clone());
+        live.input(1).await;
+        live.inter_join().await;
+        assert_eq!( *log.lock().unwrap(), vec![1,0]);
+    });
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_shutdown() {
+    pub struct Actor(Arc<Mutex<Vec<i8>>>);
+    #[life(lib = "async_std", shutdown(on_stop = "stop"))]
+    impl Actor {
+        pub fn new(log: Arc<Mutex<Vec<i8>>>) -> Self{ Self(log) }
+        pub fn input(&mut self, v:i8){ self.0.lock().unwrap().push(v) }
+        fn stop(&mut self){ self.0.lock().unwrap().push(0) }
+    }
+    async_std::task::block_on(async {
+        let log = Arc::new(Mutex::new(vec![]));
+        let mut live = ActorLive::new(log.clone());
+        live.input(1).await;
+        live.inter_join().await;
+        assert_eq!( *log.lock().unwrap(), vec![1,0]);
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_shutdown() {
+    pub struct Actor(Arc<Mutex<Vec<i8>>>);
+    #[life(lib = "smol", shutdown(on_stop = "stop"))]
+    impl Actor {
+        pub fn new(log: Arc<Mutex<Vec<i8>>>) -> Self{ Self(log) }
+        pub fn input(&mut self, v:i8){ self.0.lock().unwrap().push(v) }
+        fn stop(&mut self){ self.0.lock().unwrap().push(0) }
+    }
+    smol::block_on(async {
+        let log = Arc::new(Mutex::new(vec![]));
+        let mut live = ActorLive::new(log.clone());
+        live.input(1).await;
+        live.inter_join().await;
+        assert_eq!( *log.lock().unwrap(), vec![1,0]);
+    });
+}
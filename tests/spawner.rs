@@ -0,0 +1,174 @@
+use interthread::actor as life;
+use std::future::Future;
+use std::sync::OnceLock;
+
+fn thread_name() -> Option<String> {
+    std::thread::current().name().map(String::from)
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_new_on() {
+    pub struct Actor(u8);
+    #[life(lib = "tokio", spawner)]
+    impl Actor {
+        pub fn new(v: u8) -> Self{ Self(v) }
+        pub fn value(&self) -> u8{ self.0 }
+        pub fn name(&self) -> Option<String>{ thread_name() }
+    }
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .thread_name("io")
+        .enable_all()
+        .build()
+        .unwrap();
+    // outside of a runtime context
+    let live = ActorLive::new_on(runtime.handle().clone(), 3);
+    runtime.block_on(async {
+        assert_eq!( live.value().await, 3);
+        assert_eq!( live.name().await.as_deref(), Some("io"));
+    });
+}
+
+#[test]
+fn actor_tokio_spawner() {
+    pub struct Actor;
+    #[life(lib = "tokio", spawner = compute_spawn, handle)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn name(&self) -> Option<String>{ thread_name() }
+    }
+    static COMPUTE: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+    fn compute_spawn<F>(future: F) -> tokio::task::JoinHandle<F::Output>
+    where F: Future + Send + 'static, F::Output: Send + 'static,
+    {
+        COMPUTE.get_or_init(|| {
+            tokio::runtime::Builder::new_multi_thread()
+                .worker_threads(1)
+                .thread_name("compute")
+                .build()
+                .unwrap()
+        }).spawn(future)
+    }
+    let live = ActorLive::new();
+    let handle = live.inter_take_handle().unwrap();
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    runtime.block_on(async {
+        assert_eq!( live.name().await.as_deref(), Some("compute"));
+        drop(live);
+        assert!( handle.await.is_ok());
+    });
+}
+
+#[test]
+fn actor_tokio_try_new_on() {
+    pub struct Actor(u8);
+    #[life(lib = "tokio", spawner)]
+    impl Actor {
+        pub fn try_new(v: u8) -> Option<Self>{ Some(Self(v)) }
+        pub fn value(&self) -> u8{ self.0 }
+    }
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    let live = ActorLive::try_new_on(runtime.handle().clone(), 5).unwrap();
+    runtime.block_on(async {
+        assert_eq!( live.value().await, 5);
+    });
+}
+
+#[test]
+fn actor_tokio_spawner_local_set() {
+    pub struct Actor(u8);
+    #[life(lib = "tokio", spawner = tokio::task::spawn_local)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn input(&mut self, v: u8){ self.0 = v }
+        pub fn value(&self) -> u8{ self.0 }
+    }
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    let local = tokio::task::LocalSet::new();
+    local.block_on(&runtime, async {
+        let mut live = ActorLive::new();
+        live.input(6).await;
+        assert_eq!( live.value().await, 6);
+    });
+}
+
+#[test]
+fn actor_tokio_method_new_on() {
+    // no constructor is generated without `spawner`
+    pub struct Actor(u8);
+    #[life(lib = "tokio")]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn new_on(&mut self, v: u8){ self.0 = v }
+        pub fn value(&self) -> u8{ self.0 }
+    }
+    tokio::runtime::Runtime::new().unwrap().block_on(async {
+        let mut live = ActorLive::new();
+        live.new_on(4).await;
+        assert_eq!( live.value().await, 4);
+    });
+}
+
+// ASYNC_STD
+#[test]
+fn actor_async_std_spawner() {
+    pub struct Actor;
+    #[life(lib = "async_std", spawner = named_spawn)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn name(&self) -> Option<String>{ 
+            async_std::task::current().name().map(String::from)
+        }
+    }
+    fn named_spawn<F>(future: F) -> async_std::task::JoinHandle<F::Output>
+    where F: Future + Send + 'static, F::Output: Send + 'static,
+    {
+        async_std::task::Builder::new().name("actor".into()).spawn(future).unwrap()
+    }
+    let live = ActorLive::new();
+    async_std::task::block_on(async {
+        assert_eq!( live.name().await.as_deref(), Some("actor"));
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_new_on() {
+    pub struct Actor(u8);
+    #[life(lib = "smol", spawner, handle)]
+    impl Actor {
+        pub fn new(v: u8) -> Self{ Self(v) }
+        pub fn value(&self) -> u8{ self.0 }
+    }
+    let executor = smol::Executor::new();
+    let live = ActorLive::new_on(&executor, 7);
+    let handle = live.inter_take_handle().unwrap();
+    smol::block_on(executor.run(async {
+        assert_eq!( live.value().await, 7);
+        drop(live);
+        handle.await;
+    }));
+}
+
+#[test]
+fn actor_smol_spawner() {
+    pub struct Actor(u8);
+    #[life(lib = "smol", spawner = local_spawn)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn input(&mut self, v: u8){ self.0 = v }
+        pub fn value(&self) -> u8{ self.0 }
+    }
+    static EXECUTOR: smol::Executor<'static> = smol::Executor::new();
+    fn local_spawn<F>(future: F) -> smol::Task<F::Output>
+    where F: Future + Send + 'static, F::Output: Send + 'static,
+    {
+        EXECUTOR.spawn(future)
+    }
+    let mut live = ActorLive::new();
+    smol::block_on(EXECUTOR.run(async {
+        live.input(9).await;
+        assert_eq!( live.value().await, 9);
+    }));
+}
@@ -0,0 +1,76 @@
+use interthread::actor as life;
+
+#[test]
+fn actor_supervise_restart() {
+    pub struct Actor{ value: i8, restarts: usize }
+    #[life(supervise(restart = 3, on_restart = "restarted"))]
+    impl Actor {
+        pub fn new(value: i8) -> Self{ Self{ value, restarts: 0 } }
+        pub fn input(&mut self, v:i8){ self.value = v }
+        pub fn output(&self)->i8{ self.value }
+        pub fn restarts(&self)->usize{ self.restarts }
+        pub fn crash(&self){ panic!("crash") }
+        fn restarted(&mut self, restarts: usize){ self.restarts = restarts }
+    }
+    let mut live = ActorLive::new(1);
+    live.input(5);
+    assert_eq!( live.output(), 5);
+
+    live.crash();
+    // rebuilt from the original `new` arguments
+    assert_eq!( live.output(), 1);
+    assert_eq!( live.restarts(), 1);
+
+    live.crash();
+    assert_eq!( live.restarts(), 2);
+}
+
+#[test]
+fn actor_supervise_restart_limit() {
+    pub struct Actor(i8);
+    #[life(supervise(restart = 1), fallible)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn output(&self)->i8{ self.0 }
+        pub fn crash(&self){ panic!("crash") }
+    }
+    let live = ActorLive::new();
+    assert_eq!( live.crash(), Ok(()));
+    assert_eq!( live.output(), Ok(0));
+
+    // the limit is reached, the actor ends
+    assert_eq!( live.crash(), Ok(()));
+    assert!( live.output().is_err());
+    assert_eq!( live.output(), Err(ActorError::SendClosed));
+}
+
+#[test]
+fn actor_supervise_factory() {
+    pub struct Actor(String);
+    #[life(supervise(strategy = "one_for_one", factory = fresh))]
+    impl Actor {
+        pub fn try_new(s: &'static str) -> Option<Self>{ Some(Self(s.to_string())) }
+        pub fn output(&self)->String{ self.0.clone() }
+        pub fn crash(&self){ panic!("crash") }
+        fn fresh() -> Self { Self("fresh".to_string()) }
+    }
+    let live = ActorLive::try_new("first").unwrap();
+    assert_eq!( live.output(), "first".to_string());
+    live.crash();
+    assert_eq!( live.output(), "fresh".to_string());
+}
+
+#[test]
+fn actor_supervise_try_new() {
+    pub struct Actor(u32);
+    #[life(supervise, channel = 2)]
+    impl Actor {
+        pub fn try_new(v: u32) -> Result<Self,String>{ Ok(Self(v)) }
+        pub fn add(&mut self, v: u32)->u32{ self.0 += v; self.0 }
+        pub fn crash(&self){ panic!("crash") }
+    }
+    let mut live = ActorLive::try_new(10).unwrap();
+    assert_eq!( live.add(1), 11);
+    live.crash();
+    assert_eq!( live.add(1), 11);
+}
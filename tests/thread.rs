@@ -0,0 +1,76 @@
+use interthread::actor as life;
+
+fn thread_name() -> Option<String> {
+    std::thread::current().name().map(String::from)
+}
+
+#[test]
+fn actor_sync_thread_name() {
+    pub struct Actor;
+    #[life(thread(name = "worker"))]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn name(&self) -> Option<String>{ thread_name() }
+    }
+    let live = ActorLive::new();
+    assert_eq!( live.name().as_deref(), Some("worker"));
+}
+
+#[test]
+fn actor_sync_thread_default_name() {
+    pub struct Actor;
+    #[life(thread, name = "Counter", handle)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn name(&self) -> Option<String>{ thread_name() }
+    }
+    let live = CounterLive::new();
+    let handle = live.inter_take_handle().unwrap();
+    assert_eq!( handle.thread().name(), Some("Counter"));
+    assert_eq!( live.name().as_deref(), Some("Counter"));
+}
+
+#[test]
+fn actor_sync_thread_debut_name() {
+    pub struct Actor;
+    #[life(thread(stack_size = 262144), debut)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn name(&self) -> Option<String>{ thread_name() }
+    }
+    let live = ActorLive::new();
+    let millis = live.debut.duration_since(std::time::UNIX_EPOCH).unwrap().as_millis();
+    let name = format!("Actor-{millis}");
+    assert_eq!( live.name(), Some(name));
+}
+
+// `stack_size` is a `usize`, the value only fits on 64 bit targets
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn actor_sync_thread_try_new() {
+    pub struct Actor;
+    #[derive(Debug)]
+    pub struct Error(std::io::ErrorKind);
+    impl From<std::io::Error> for Error {
+        fn from(error: std::io::Error) -> Self { Self(error.kind()) }
+    }
+    #[life(thread(stack_size = 18446744073709551615))]
+    impl Actor {
+        pub fn try_new() -> Result<Self,Error>{ Ok(Self) }
+        pub fn name(&self) -> Option<String>{ thread_name() }
+    }
+    // the stack size can not be allocated
+    assert!( ActorLive::try_new().err().map(|error| error.0).is_some());
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn actor_sync_thread_option_new() {
+    pub struct Actor;
+    #[life(thread(stack_size = 18446744073709551615))]
+    impl Actor {
+        pub fn try_new() -> Option<Self>{ Some(Self) }
+        pub fn name(&self) -> Option<String>{ thread_name() }
+    }
+    assert!( ActorLive::try_new().is_none());
+}
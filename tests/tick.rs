@@ -0,0 +1,108 @@
+use interthread::actor as life;
+use std::time::Duration;
+
+// STD
+#[test]
+fn actor_sync_tick() {
+    pub struct Actor{ ticks: usize, value: i8 }
+    #[life(tick(every = "10ms", method = on_tick))]
+    impl Actor {
+        pub fn new() -> Self{ Self{ ticks: 0, value: 0 } }
+        pub fn input(&mut self, v:i8){ self.value = v }
+        pub fn output(&self)->i8{ self.value }
+        pub fn ticks(&self)->usize{ self.ticks }
+        fn on_tick(&mut self){ self.ticks += 1 }
+    }
+    let mut live = ActorLive::new();
+    assert_eq!( live.ticks(), 0);
+    std::thread::sleep(Duration::from_millis(100));
+    live.input(3);
+    assert_eq!( live.output(), 3);
+    assert!( live.ticks() >= 3);
+}
+
+#[test]
+fn actor_sync_tick_busy() {
+    pub struct Actor(usize);
+    #[life(tick(every = "5ms", method = "on_tick"), shutdown)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn ticks(&self)->usize{ self.0 }
+        pub fn work(&self){ std::thread::sleep(Duration::from_millis(2)) }
+        fn on_tick(&mut self){ self.0 += 1 }
+    }
+    let live = ActorLive::new();
+    // the channel is never empty
+    for _ in 0..50 { live.work(); }
+    assert!( live.ticks() >= 5);
+    live.inter_join();
+}
+
+#[test]
+fn actor_sync_tick_missed() {
+    pub struct Actor(usize);
+    #[life(tick(every = "5ms", method = on_tick))]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn ticks(&self)->usize{ self.0 }
+        pub fn block(&self){ std::thread::sleep(Duration::from_millis(60)) }
+        fn on_tick(&mut self){ self.0 += 1 }
+    }
+    let live = ActorLive::new();
+    live.block();
+    // the ticks missed while blocked are skipped
+    assert!( live.ticks() <= 3);
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_tick() {
+    pub struct Actor(usize);
+    #[life(lib = "tokio", tick(every = "10ms", method = on_tick))]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn ticks(&self)->usize{ self.0 }
+        async fn on_tick(&mut self){ self.0 += 1 }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let live = ActorLive::new();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!( live.ticks().await >= 3);
+    });
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_tick() {
+    pub struct Actor(usize);
+    #[life(lib = "async_std", tick(every = "10ms", method = on_tick))]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn ticks(&self)->usize{ self.0 }
+        fn on_tick(&mut self){ self.0 += 1 }
+    }
+    async_std::task::block_on(async {
+        let live = ActorLive::new();
+        async_std::task::sleep(Duration::from_millis(100)).await;
+        assert!( live.ticks().await >= 3);
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_tick() {
+    pub struct Actor(usize);
+    #[life(lib = "smol", tick(every = "10ms", method = on_tick))]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn ticks(&self)->usize{ self.0 }
+        fn on_tick(&mut self){ self.0 += 1 }
+    }
+    smol::block_on(async {
+        let live = ActorLive::new();
+        smol::Timer::after(Duration::from_millis(100)).await;
+        assert!( live.ticks().await >= 3);
+    });
+}
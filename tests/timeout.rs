@@ -0,0 +1,97 @@
+use interthread::actor as life;
+use std::time::Duration;
+
+// STD
+#[test]
+fn actor_sync_timeout() {
+    pub struct Actor(u64);
+    #[life(timeout(default = "50ms", patient = "5s"))]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn input(&mut self, v:u64){ self.0 = v }
+        pub fn output(&self)->u64{ self.0 }
+        pub fn sleep(&self, ms: u64)->u64{ std::thread::sleep(Duration::from_millis(ms)); ms }
+        pub fn patient(&self, ms: u64)->u64{ std::thread::sleep(Duration::from_millis(ms)); ms }
+    }
+    let mut live = ActorLive::new();
+    // not timed
+    live.input(3);
+    assert_eq!( live.output(), Ok(3));
+    assert_eq!( live.sleep(0),  Ok(0));
+    assert_eq!( live.sleep(500), Err(ActorError::Timeout));
+    assert_eq!( live.patient(100), Ok(100));
+    // the actor is still running
+    assert_eq!( live.output(), Ok(3));
+    assert_eq!( ActorError::Timeout.to_string(), "'ActorLive' reply timed out.");
+}
+
+#[test]
+fn actor_sync_timeout_method() {
+    pub struct Actor;
+    #[life(timeout(sleep = "50ms", r#default = "50ms"))]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn sleep(&self, ms: u64)->u64{ std::thread::sleep(Duration::from_millis(ms)); ms }
+        pub fn default(&self, ms: u64)->u64{ std::thread::sleep(Duration::from_millis(ms)); ms }
+        pub fn value(&self)->u64{ 7 }
+    }
+    let live = ActorLive::new();
+    assert_eq!( live.sleep(500), Err(ActorError::Timeout));
+    // not the `default` key
+    assert_eq!( live.default(500), Err(ActorError::Timeout));
+    // not timed
+    assert_eq!( live.value(), 7);
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_timeout() {
+    pub struct Actor;
+    #[life(lib = "tokio", timeout(default = "50ms", patient = "5s"))]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub async fn sleep(&self, ms: u64)->u64{ tokio::time::sleep(Duration::from_millis(ms)).await; ms }
+        pub async fn patient(&self, ms: u64)->u64{ tokio::time::sleep(Duration::from_millis(ms)).await; ms }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let live = ActorLive::new();
+        assert_eq!( live.sleep(0).await,   Ok(0));
+        assert_eq!( live.sleep(500).await, Err(ActorError::Timeout));
+        // the actor is still running
+        assert_eq!( live.patient(1).await, Ok(1));
+    });
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_timeout() {
+    pub struct Actor;
+    #[life(lib = "async_std", timeout = "50ms")]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub async fn sleep(&self, ms: u64)->u64{ async_std::task::sleep(Duration::from_millis(ms)).await; ms }
+    }
+    async_std::task::block_on(async {
+        let live = ActorLive::new();
+        assert_eq!( live.sleep(0).await,   Ok(0));
+        assert_eq!( live.sleep(500).await, Err(ActorError::Timeout));
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_timeout() {
+    pub struct Actor;
+    #[life(lib = "smol", timeout = "50ms", fallible)]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub async fn sleep(&self, ms: u64)->u64{ smol::Timer::after(Duration::from_millis(ms)).await; ms }
+    }
+    smol::block_on(async {
+        let live = ActorLive::new();
+        assert_eq!( live.sleep(0).await,   Ok(0));
+        assert_eq!( live.sleep(500).await, Err(ActorError::Timeout));
+    });
+}
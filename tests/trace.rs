@@ -0,0 +1,172 @@
+use interthread::actor as life;
+use std::collections::BTreeMap;
+use std::sync::{Mutex,Once};
+use tracing::span::{Attributes,Id,Record};
+use tracing_core::span::Current;
+use tracing::{Event,Metadata,Subscriber};
+
+// records the spans ( name, parent name ) and
+// the events ( actor, message ) of every test
+#[derive(Default)]
+struct Recorder {
+    spans:  Mutex<BTreeMap<u64,(&'static Metadata<'static>,Option<u64>)>>,
+    events: Mutex<Vec<(String,String)>>,
+}
+
+static RECORDER: Recorder = Recorder{ spans: Mutex::new(BTreeMap::new()), events: Mutex::new(Vec::new()) };
+static INIT: Once = Once::new();
+
+thread_local!{
+    static STACK: std::cell::RefCell<Vec<u64>> = std::cell::RefCell::new(Vec::new());
+}
+
+struct Visitor(String,String);
+impl tracing::field::Visit for Visitor {
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str){
+        if field.name() == "actor" { self.0 = value.to_string() }
+    }
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug){
+        if field.name() == "message" { self.1 = format!("{value:?}") }
+    }
+}
+
+struct Global;
+impl Subscriber for Global {
+    fn enabled(&self, _: &Metadata<'_>) -> bool { true }
+    fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+        let parent = 
+        if attrs.is_contextual() { STACK.with(|s| s.borrow().last().cloned()) }
+        else { attrs.parent().map(|id| id.into_u64()) };
+        let mut spans = RECORDER.spans.lock().unwrap();
+        let id = spans.len() as u64 + 1;
+        spans.insert(id,(attrs.metadata(),parent));
+        Id::from_u64(id)
+    }
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+    fn event(&self, event: &Event<'_>) {
+        let mut visitor = Visitor(String::new(),String::new());
+        event.record(&mut visitor);
+        RECORDER.events.lock().unwrap().push((visitor.0,visitor.1));
+    }
+    fn enter(&self, span: &Id) { STACK.with(|s| s.borrow_mut().push(span.into_u64())) }
+    fn exit(&self, _: &Id) { STACK.with(|s| s.borrow_mut().pop()); }
+    fn current_span(&self) -> Current {
+        match STACK.with(|s| s.borrow().last().cloned()) {
+            Some(id) => {
+                let metadata = RECORDER.spans.lock().unwrap()[&id].0;
+                Current::new(Id::from_u64(id), metadata)
+            },
+            None => Current::none(),
+        }
+    }
+}
+
+fn init(){
+    INIT.call_once(|| tracing::subscriber::set_global_default(Global).unwrap());
+}
+
+// the spans of an actor as ( span name, parent span name )
+fn spans(actor: &str) -> Vec<(String,Option<String>)> {
+    let spans = RECORDER.spans.lock().unwrap();
+    spans.values()
+    .filter(|(m,_)| m.name().starts_with(actor))
+    .map(|(m,p)| (m.name().to_string(), p.map(|p| spans[&p].0.name().to_string())))
+    .collect()
+}
+
+fn events(actor: &str) -> Vec<String> {
+    RECORDER.events.lock().unwrap().iter()
+    .filter(|(a,_)| a == actor)
+    .map(|(_,m)| m.clone())
+    .collect()
+}
+
+// STD
+#[test]
+fn actor_sync_trace() {
+    init();
+    pub struct SyncActor(i8);
+    #[life(trace)]
+    impl SyncActor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn input(&mut self, v:i8){ self.0 = v }
+        pub fn output(&self)->i8{ self.0 }
+    }
+    let mut live = SyncActorLive::new();
+    tracing::info_span!("caller").in_scope(|| {
+        live.input(3);
+        assert_eq!( live.output(), 3);
+    });
+    assert_eq!( spans("SyncActor"), vec![
+        ("SyncActor::input".to_string(), Some("caller".to_string())),
+        ("SyncActor::output".to_string(),Some("caller".to_string())),
+    ]);
+    assert_eq!( events("SyncActor"), vec!["enqueue","enqueue","reply"]);
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_trace() {
+    init();
+    pub struct TokioActor(u8);
+    #[life(lib = "tokio", trace, debut, fallible)]
+    impl TokioActor {
+        pub fn new() -> Self{ Self(0) }
+        pub async fn add(&mut self, v:u8)->u8{ 
+            // within the span of the message
+            tracing::info_span!("inner").in_scope(|| ());
+            self.0 += v; self.0 
+        }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let mut live = TokioActorLive::new();
+        assert_eq!( live.add(1).await, Ok(1));
+    });
+    assert_eq!( spans("TokioActor"), vec![("TokioActor::add".to_string(), None)]);
+    assert_eq!( spans("inner"), vec![("inner".to_string(), Some("TokioActor::add".to_string()))]);
+    assert_eq!( events("TokioActor"), vec!["enqueue","reply"]);
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_trace() {
+    init();
+    pub struct AsyncStdActor;
+    #[life(lib = "async_std", trace, shutdown)]
+    impl AsyncStdActor {
+        pub fn new() -> Self{ Self }
+        pub fn ping(&self)->bool{ true }
+    }
+    async_std::task::block_on(async {
+        let live = AsyncStdActorLive::new();
+        assert!( live.ping().await);
+        live.inter_join().await;
+    });
+    assert_eq!( spans("AsyncStdActor"), vec![
+        ("AsyncStdActor::ping".to_string(), None),
+    ]);
+}
+
+// SMOL
+#[test]
+fn actor_smol_trace() {
+    init();
+    pub struct SmolActor(u8);
+    #[life(lib = "smol", trace, with)]
+    impl SmolActor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn add(&mut self, v:u8){ self.0 += v }
+    }
+    smol::block_on(async {
+        let mut live = SmolActorLive::new();
+        live.add(2).await;
+        assert_eq!( live.with(|a| a.0).await, 2);
+    });
+    assert_eq!( spans("SmolActor"), vec![
+        ("SmolActor::add".to_string(), None),
+        ("SmolActor::with".to_string(), None),
+    ]);
+}
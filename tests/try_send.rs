@@ -0,0 +1,133 @@
+use interthread::actor as life;
+use std::sync::{Arc,Barrier};
+
+// STD
+#[test]
+fn actor_sync_try_send() {
+    pub struct Actor(i8, Arc<Barrier>);
+    #[life(channel = 1, try_send, handle)]
+    impl Actor {
+        pub fn new(barrier: Arc<Barrier>) -> Self{ Self(0,barrier) }
+        pub fn input(&mut self, v:i8){ self.0 = v }
+        pub fn output(&self)->i8{ self.0 }
+        pub fn in_out(&self,v:i8)->i8{ v }
+        pub fn pause(&self){ self.1.wait(); self.1.wait(); }
+        pub fn crash(&self)->i8{ panic!("crash") }
+    }
+    let barrier = Arc::new(Barrier::new(2));
+    let mut live = ActorLive::new(barrier.clone());
+    assert_eq!( live.try_input(3), Ok(()));
+    assert_eq!( live.output(), 3);
+    // sent without blocking, then the reply is awaited
+    assert_eq!( live.try_output(), Ok(3));
+    assert_eq!( live.try_in_out(4), Ok(4));
+
+    // the actor is busy and the channel is full
+    live.pause();
+    barrier.wait();
+    assert_eq!( live.try_input(5), Ok(()));
+    assert_eq!( live.try_input(6), Err(ActorError::Full));
+    assert_eq!( live.try_pause(), Err(ActorError::Full));
+    assert_eq!( live.try_output(), Err(ActorError::Full));
+    barrier.wait();
+    assert_eq!( live.output(), 5);
+
+    assert_eq!( live.try_crash(), Err(ActorError::ReplyDropped));
+    assert!( live.inter_take_handle().unwrap().join().is_err());
+    assert_eq!( live.try_input(7), Err(ActorError::SendClosed));
+    assert_eq!( ActorError::Full.to_string(), "'ActorLive' channel is full.");
+}
+
+#[test]
+fn actor_sync_try_send_timeout() {
+    pub struct Actor;
+    #[life(channel = 1, try_send, timeout(slow = "50ms"))]
+    impl Actor {
+        pub fn new() -> Self{ Self }
+        pub fn slow(&self, ms: u64)->u64{ std::thread::sleep(std::time::Duration::from_millis(ms)); ms }
+    }
+    let live = ActorLive::new();
+    assert_eq!( live.try_slow(0), Ok(0));
+    assert_eq!( live.try_slow(500), Err(ActorError::Timeout));
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_try_send() {
+    pub struct Actor(i8, Arc<Barrier>);
+    #[life(channel = 1, lib = "tokio", try_send, fallible)]
+    impl Actor {
+        pub fn new(barrier: Arc<Barrier>) -> Self{ Self(0,barrier) }
+        pub fn input(&mut self, v:i8){ self.0 = v }
+        pub fn output(&self)->i8{ self.0 }
+        pub fn pause(&self){ self.1.wait(); self.1.wait(); }
+    }
+    tokio::runtime::Builder::new_multi_thread()
+    .worker_threads(2)
+    .build()
+    .unwrap()
+    .block_on(async {
+        let barrier = Arc::new(Barrier::new(2));
+        let mut live = ActorLive::new(barrier.clone());
+        assert_eq!( live.try_input(3), Ok(()));
+        assert_eq!( live.output().await, Ok(3));
+        assert_eq!( live.try_output().await, Ok(3));
+        assert_eq!( live.pause().await, Ok(()));
+        barrier.wait();
+        assert_eq!( live.try_input(5), Ok(()));
+        assert_eq!( live.try_input(6), Err(ActorError::Full));
+        assert_eq!( live.try_output().await, Err(ActorError::Full));
+        barrier.wait();
+        assert_eq!( live.output().await, Ok(5));
+    });
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_try_send() {
+    pub struct Actor(i8, Arc<Barrier>);
+    #[life(channel = 1, lib = "async_std", try_send)]
+    impl Actor {
+        pub fn new(barrier: Arc<Barrier>) -> Self{ Self(0,barrier) }
+        pub fn input(&mut self, v:i8){ self.0 = v }
+        pub fn output(&self)->i8{ self.0 }
+        pub fn pause(&self){ self.1.wait(); self.1.wait(); }
+    }
+    async_std::task::block_on(async {
+        let barrier = Arc::new(Barrier::new(2));
+        let mut live = ActorLive::new(barrier.clone());
+        live.pause().await;
+        barrier.wait();
+        assert_eq!( live.try_input(5), Ok(()));
+        assert_eq!( live.try_input(6), Err(ActorError::Full));
+        assert_eq!( live.try_output().await, Err(ActorError::Full));
+        barrier.wait();
+        assert_eq!( live.output().await, 5);
+        assert_eq!( live.try_output().await, Ok(5));
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_try_send() {
+    pub struct Actor(i8, Arc<Barrier>);
+    #[life(channel = 1, lib = "smol", try_send)]
+    impl Actor {
+        pub fn new(barrier: Arc<Barrier>) -> Self{ Self(0,barrier) }
+        pub fn input(&mut self, v:i8){ self.0 = v }
+        pub fn output(&self)->i8{ self.0 }
+        pub fn pause(&self){ self.1.wait(); self.1.wait(); }
+    }
+    smol::block_on(async {
+        let barrier = Arc::new(Barrier::new(2));
+        let mut live = ActorLive::new(barrier.clone());
+        live.pause().await;
+        barrier.wait();
+        assert_eq!( live.try_input(5), Ok(()));
+        assert_eq!( live.try_input(6), Err(ActorError::Full));
+        assert_eq!( live.try_output().await, Err(ActorError::Full));
+        barrier.wait();
+        assert_eq!( live.output().await, 5);
+        assert_eq!( live.try_output().await, Ok(5));
+    });
+}
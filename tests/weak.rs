@@ -0,0 +1,107 @@
+use interthread::actor as life;
+
+// STD
+#[test]
+fn actor_sync_weak() {
+    pub struct Actor(i8);
+    #[life(weak, handle)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn input(&mut self, v:i8){ self.0 = v }
+        pub fn output(&self)->i8{ self.0 }
+    }
+    let mut live = ActorLive::new();
+    let weak = live.downgrade();
+    live.input(3);
+    assert_eq!( weak.upgrade().map(|live| live.output()), Some(3));
+
+    let handle = live.inter_take_handle().unwrap();
+    let other  = weak.clone().upgrade().unwrap();
+    drop(live);
+    assert_eq!( other.output(), 3);
+    drop(other);
+    // the weak instances do not keep the actor alive
+    assert!( handle.join().is_ok());
+    assert!( weak.upgrade().is_none());
+}
+
+#[test]
+fn actor_sync_weak_cycle() {
+    pub struct Node{ peer: Option<NodeWeakLive> }
+    #[life(name = "Node", weak, handle, debut)]
+    impl Node {
+        pub fn new() -> Self{ Self{ peer: None } }
+        pub fn set_peer(&mut self, peer: NodeWeakLive){ self.peer = Some(peer) }
+        pub fn has_peer(&self)->bool{ self.peer.is_some() }
+    }
+    let mut a = NodeLive::new();
+    let mut b = NodeLive::new();
+    a.set_peer(b.downgrade());
+    b.set_peer(a.downgrade());
+    assert!( a.has_peer() && b.has_peer());
+    // only the live instances are counted
+    assert_eq!( a.inter_get_count(), 1);
+
+    let handles = [a.inter_take_handle().unwrap(), b.inter_take_handle().unwrap()];
+    drop(a);
+    drop(b);
+    for handle in handles { assert!( handle.join().is_ok()); }
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_weak() {
+    pub struct Actor(u8);
+    #[life(lib = "tokio", weak, pool(size = 2), channel = 2)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub async fn add(&mut self, v:u8)->u8{ self.0 += v; self.0 }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let mut live = ActorLive::new();
+        let weak = live.downgrade();
+        assert_eq!( live.add(1).await, 1);
+        assert_eq!( weak.upgrade().unwrap().add(1).await, 1);
+        drop(live);
+        assert!( weak.upgrade().is_none());
+    });
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_weak() {
+    pub struct Actor(u8);
+    #[life(lib = "async_std", weak)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn add(&mut self, v:u8)->u8{ self.0 += v; self.0 }
+    }
+    async_std::task::block_on(async {
+        let mut live = ActorLive::new();
+        let weak = live.downgrade();
+        assert_eq!( live.add(1).await, 1);
+        assert_eq!( weak.upgrade().unwrap().add(1).await, 2);
+        drop(live);
+        assert!( weak.upgrade().is_none());
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_weak() {
+    pub struct Actor(u8);
+    #[life(lib = "smol", weak, priority(high(add)))]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn add(&mut self, v:u8)->u8{ self.0 += v; self.0 }
+    }
+    smol::block_on(async {
+        let mut live = ActorLive::new();
+        let weak = live.downgrade();
+        assert_eq!( weak.upgrade().unwrap().add(2).await, 2);
+        drop(live);
+        assert!( weak.upgrade().is_none());
+    });
+}
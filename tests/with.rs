@@ -0,0 +1,83 @@
+use interthread::actor as life;
+
+// STD
+#[test]
+fn actor_sync_with() {
+    pub struct Actor{ value: i8, log: Vec<String> }
+    #[life(with)]
+    impl Actor {
+        pub fn new() -> Self{ Self{ value: 0, log: vec![] } }
+        pub fn input(&mut self, v:i8){ self.value = v; self.log.push(format!("input {v}")) }
+    }
+    let mut live = ActorLive::new();
+    live.input(3);
+    assert_eq!( live.with(|actor| actor.value), 3);
+    assert_eq!( live.with(|actor| actor.log.clone()), vec!["input 3".to_string()]);
+    live.with_mut(|actor| actor.value += 1);
+    assert_eq!( live.with(|actor| actor.value), 4);
+}
+
+#[test]
+fn actor_sync_with_generic() {
+    pub struct Actor<T>(Vec<T>);
+    #[life(with, fallible)]
+    impl<T: Clone> Actor<T> {
+        pub fn new() -> Self{ Self(vec![]) }
+        pub fn push(&mut self, v:T){ self.0.push(v) }
+    }
+    let mut live = ActorLive::<u8>::new();
+    assert_eq!( live.push(1), Ok(()));
+    assert_eq!( live.with_mut(|actor| { actor.0.push(2); actor.0.len() }), Ok(2));
+    assert_eq!( live.with(|actor| actor.0.clone()), Ok(vec![1,2]));
+}
+
+// TOKIO
+#[test]
+fn actor_tokio_with() {
+    pub struct Actor(i8);
+    #[life(lib = "tokio", with)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub async fn input(&mut self, v:i8){ self.0 = v }
+    }
+    tokio::runtime::Runtime::new()
+    .unwrap()
+    .block_on(async {
+        let mut live = ActorLive::new();
+        live.input(3).await;
+        assert_eq!( live.with(|actor| actor.0).await, 3);
+        live.with_mut(|actor| actor.0 = 5).await;
+        assert_eq!( live.with(|actor| actor.0 * 2).await, 10);
+    });
+}
+
+// ASYNC-STD
+#[test]
+fn actor_async_std_with() {
+    pub struct Actor(i8);
+    #[life(lib = "async_std", with)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+        pub fn input(&mut self, v:i8){ self.0 = v }
+    }
+    async_std::task::block_on(async {
+        let mut live = ActorLive::new();
+        live.input(3).await;
+        assert_eq!( live.with(|actor| actor.0).await, 3);
+    });
+}
+
+// SMOL
+#[test]
+fn actor_smol_with() {
+    pub struct Actor(i8);
+    #[life(lib = "smol", with)]
+    impl Actor {
+        pub fn new() -> Self{ Self(0) }
+    }
+    smol::block_on(async {
+        let mut live = ActorLive::new();
+        live.with_mut(|actor| actor.0 = 7).await;
+        assert_eq!( live.with(|actor| actor.0).await, 7);
+    });
+}